@@ -0,0 +1,88 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use common::{
+    input::input_dir,
+    parse::{self, ParseError},
+};
+
+/// The expected answers of the puzzles, in the format printed by `aoc run`:
+///
+/// ```text
+/// # comments and blank lines are ignored
+/// day 05 part 1: 35
+/// day 05 part 2: 46
+/// ```
+#[derive(Debug, PartialEq, Default)]
+pub struct Answers {
+    pub answers: BTreeMap<(u8, u8), String>,
+}
+
+impl Answers {
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+}
+
+impl TryFrom<&str> for Answers {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut answers = BTreeMap::new();
+        for (index, line) in input.lines().enumerate() {
+            let line = line.trim_end();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (day, part, answer) = parse_line(line).map_err(|error| error.on_line(index))?;
+            answers.insert((day, part), answer.to_string());
+        }
+        Ok(Self { answers })
+    }
+}
+
+fn parse_line(line: &str) -> Result<(u8, u8, &str), ParseError> {
+    let (puzzle, answer) = line
+        .split_once(": ")
+        .ok_or_else(|| ParseError::after(line, "`: <answer>`"))?;
+    let (day, part) = puzzle
+        .strip_prefix("day ")
+        .and_then(|puzzle| puzzle.split_once(" part "))
+        .ok_or_else(|| ParseError::at(line, puzzle, "`day <DAY> part <PART>`"))?;
+    let day = parse::number(line, day)?;
+    let part_number = parse::number(line, part)?;
+    if !(1..=2).contains(&part_number) {
+        return Err(ParseError::at(line, part, "part 1 or 2"));
+    }
+    Ok((day, part_number, answer.trim()))
+}
+
+/// The default answers file: `input/answers`, next to the puzzle inputs.
+pub fn default_path() -> PathBuf {
+    input_dir().join("answers")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::Answers;
+
+    #[test]
+    fn parse() {
+        let answers =
+            Answers::try_from("# real input\nday 05 part 1: 35\n\nday 7 part 2: 5905\n").unwrap();
+        assert_eq!(answers.get(5, 1), Some("35"));
+        assert_eq!(answers.get(7, 2), Some("5905"));
+        assert_eq!(answers.get(5, 2), None);
+    }
+
+    #[test]
+    fn invalid_answers() {
+        let error = Answers::try_from("day 05 part 1: 35\nday 05 part 3: 46")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (2, 13));
+        let error = Answers::try_from("day 05 part 1 35").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 17));
+        let error = Answers::try_from("day five part 1: 35").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 5));
+    }
+}
@@ -0,0 +1,372 @@
+use std::str::FromStr;
+
+use crate::days;
+
+pub const USAGE: &str = "usage:
+    aoc run --day <DAY> [--part <PART>] [--input <PATH>] [--time] [--format <FORMAT>]
+    aoc run --all [--part <PART>] [--time] [--format <FORMAT>]
+    aoc verify [--day <DAY>] [--answers <PATH>]
+    aoc bench [--day <DAY>] [--runs <RUNS>] [--output <PATH>]
+    aoc gen --day <DAY> [--seed <SEED>] [--size <SIZE>]
+
+options:
+    --day <DAY>      day to run, from 1 to 25
+    --part <PART>    part to run, 1 or 2 (both by default)
+    --input <PATH>   input file, `-` for stdin (input/day-XX by default)
+    --all            run every day on its default input
+    --time           print how long parsing and each part took
+    --format <FORMAT> `text` (default), or `json` for a JSON object per part with its answer,
+                     type, timings and error
+    --answers <PATH> expected answers, as printed by `aoc run` (input/answers by default)
+    --runs <RUNS>    number of runs of each step to benchmark (10 by default)
+    --output <PATH>  CSV file the benchmark is written to (bench.csv by default)
+    --seed <SEED>    seed of the random input, the same seed giving the same input (0 by default)
+    --size <SIZE>    size of the random input, its meaning depending on the day (10 by default)";
+
+pub const DEFAULT_RUNS: usize = 10;
+pub const DEFAULT_SIZE: usize = 10;
+
+#[derive(Debug, PartialEq)]
+pub enum Selection {
+    All,
+    Day { day: u8, input: Option<String> },
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+impl TryFrom<&str> for Format {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            other => Err(format!("unknown format `{}`", other)),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RunOptions {
+    pub selection: Selection,
+    pub part: Option<u8>,
+    pub time: bool,
+    pub format: Format,
+}
+
+impl RunOptions {
+    pub fn parts(&self) -> Vec<u8> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct VerifyOptions {
+    pub day: Option<u8>,
+    pub answers: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BenchOptions {
+    pub day: Option<u8>,
+    pub runs: usize,
+    pub output: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct GenOptions {
+    pub day: u8,
+    pub seed: u64,
+    pub size: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run(RunOptions),
+    Verify(VerifyOptions),
+    Bench(BenchOptions),
+    Gen(GenOptions),
+}
+
+impl TryFrom<&[String]> for Command {
+    type Error = String;
+
+    fn try_from(args: &[String]) -> Result<Self, Self::Error> {
+        let (command, options) = args.split_first().ok_or("missing command")?;
+        match command.as_str() {
+            "run" => parse_run_options(options).map(Command::Run),
+            "verify" => parse_verify_options(options).map(Command::Verify),
+            "bench" => parse_bench_options(options).map(Command::Bench),
+            "gen" => parse_gen_options(options).map(Command::Gen),
+            other => Err(format!("unknown command `{}`", other)),
+        }
+    }
+}
+
+fn parse_run_options(args: &[String]) -> Result<RunOptions, String> {
+    let mut day = None;
+    let mut part = None;
+    let mut input = None;
+    let mut all = false;
+    let mut time = false;
+    let mut format = Format::default();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("missing value for `{}`", arg))
+        };
+        match arg.as_str() {
+            "--day" => day = Some(parse_number(value()?, "day")?),
+            "--part" => part = Some(parse_number(value()?, "part")?),
+            "--input" => input = Some(value()?.to_string()),
+            "--all" => all = true,
+            "--time" => time = true,
+            "--format" => format = Format::try_from(value()?.as_str())?,
+            other => return Err(format!("unknown option `{}`", other)),
+        }
+    }
+
+    if let Some(part) = part {
+        if !(1..=2).contains(&part) {
+            return Err(format!("there is no part {}", part));
+        }
+    }
+    let selection = match (day, all) {
+        (Some(_), true) => return Err("`--day` and `--all` cannot be used together".to_string()),
+        (None, false) => return Err("either `--day` or `--all` is required".to_string()),
+        (None, true) => {
+            if input.is_some() {
+                return Err("`--input` can only be used with `--day`".to_string());
+            }
+            Selection::All
+        }
+        (Some(day), false) => {
+            if days::find(day).is_none() {
+                return Err(format!("day {} is not solved yet", day));
+            }
+            Selection::Day { day, input }
+        }
+    };
+    Ok(RunOptions {
+        selection,
+        part,
+        time,
+        format,
+    })
+}
+
+fn parse_verify_options(args: &[String]) -> Result<VerifyOptions, String> {
+    let mut day = None;
+    let mut answers = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("missing value for `{}`", arg))
+        };
+        match arg.as_str() {
+            "--day" => day = Some(parse_number(value()?, "day")?),
+            "--answers" => answers = Some(value()?.to_string()),
+            other => return Err(format!("unknown option `{}`", other)),
+        }
+    }
+
+    check_day(day)?;
+    Ok(VerifyOptions { day, answers })
+}
+
+fn parse_bench_options(args: &[String]) -> Result<BenchOptions, String> {
+    let mut day = None;
+    let mut runs = DEFAULT_RUNS;
+    let mut output = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("missing value for `{}`", arg))
+        };
+        match arg.as_str() {
+            "--day" => day = Some(parse_number(value()?, "day")?),
+            "--runs" => runs = parse_number(value()?, "number of runs")?,
+            "--output" => output = Some(value()?.to_string()),
+            other => return Err(format!("unknown option `{}`", other)),
+        }
+    }
+
+    if runs == 0 {
+        return Err("at least one run is needed".to_string());
+    }
+    check_day(day)?;
+    Ok(BenchOptions { day, runs, output })
+}
+
+fn parse_gen_options(args: &[String]) -> Result<GenOptions, String> {
+    let mut day = None;
+    let mut seed = 0;
+    let mut size = DEFAULT_SIZE;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("missing value for `{}`", arg))
+        };
+        match arg.as_str() {
+            "--day" => day = Some(parse_number(value()?, "day")?),
+            "--seed" => seed = parse_number(value()?, "seed")?,
+            "--size" => size = parse_number(value()?, "size")?,
+            other => return Err(format!("unknown option `{}`", other)),
+        }
+    }
+
+    let day = day.ok_or("`--day` is required")?;
+    check_day(Some(day))?;
+    Ok(GenOptions { day, seed, size })
+}
+
+fn check_day(day: Option<u8>) -> Result<(), String> {
+    match day {
+        Some(day) if days::find(day).is_none() => Err(format!("day {} is not solved yet", day)),
+        _ => Ok(()),
+    }
+}
+
+fn parse_number<T: FromStr>(value: &str, name: &str) -> Result<T, String> {
+    value
+        .parse::<T>()
+        .map_err(|_| format!("invalid {} `{}`", name, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::{
+        BenchOptions, Command, Format, GenOptions, RunOptions, Selection, VerifyOptions,
+        DEFAULT_RUNS, DEFAULT_SIZE,
+    };
+
+    fn parse(args: &str) -> Result<Command, String> {
+        let args: Vec<String> = args.split_whitespace().map(|s| s.to_string()).collect();
+        Command::try_from(args.as_slice())
+    }
+
+    #[test]
+    fn run_one_part() {
+        assert_eq!(
+            parse("run --day 12 --part 2 --input some/path"),
+            Ok(Command::Run(RunOptions {
+                selection: Selection::Day {
+                    day: 12,
+                    input: Some("some/path".to_string())
+                },
+                part: Some(2),
+                time: false,
+                format: Format::Text
+            }))
+        );
+    }
+
+    #[test]
+    fn run_all() {
+        assert_eq!(
+            parse("run --all --time --format json"),
+            Ok(Command::Run(RunOptions {
+                selection: Selection::All,
+                part: None,
+                time: true,
+                format: Format::Json
+            }))
+        );
+    }
+
+    #[test]
+    fn invalid_runs() {
+        assert!(parse("").is_err());
+        assert!(parse("walk --all").is_err());
+        assert!(parse("run").is_err());
+        assert!(parse("run --day 3 --all").is_err());
+        assert!(parse("run --all --input some/path").is_err());
+        assert!(parse("run --day 42").is_err());
+        assert!(parse("run --day 3 --part 3").is_err());
+        assert!(parse("run --day three").is_err());
+        assert!(parse("run --day").is_err());
+        assert!(parse("run --all --format yaml").is_err());
+    }
+
+    #[test]
+    fn verify() {
+        assert_eq!(
+            parse("verify"),
+            Ok(Command::Verify(VerifyOptions {
+                day: None,
+                answers: None
+            }))
+        );
+        assert_eq!(
+            parse("verify --day 7 --answers my/answers"),
+            Ok(Command::Verify(VerifyOptions {
+                day: Some(7),
+                answers: Some("my/answers".to_string())
+            }))
+        );
+        assert!(parse("verify --day 42").is_err());
+        assert!(parse("verify --part 1").is_err());
+    }
+
+    #[test]
+    fn bench() {
+        assert_eq!(
+            parse("bench"),
+            Ok(Command::Bench(BenchOptions {
+                day: None,
+                runs: DEFAULT_RUNS,
+                output: None
+            }))
+        );
+        assert_eq!(
+            parse("bench --day 5 --runs 100 --output before.csv"),
+            Ok(Command::Bench(BenchOptions {
+                day: Some(5),
+                runs: 100,
+                output: Some("before.csv".to_string())
+            }))
+        );
+        assert!(parse("bench --runs 0").is_err());
+        assert!(parse("bench --runs many").is_err());
+        assert!(parse("bench --day 42").is_err());
+    }
+
+    #[test]
+    fn gen() {
+        assert_eq!(
+            parse("gen --day 10"),
+            Ok(Command::Gen(GenOptions {
+                day: 10,
+                seed: 0,
+                size: DEFAULT_SIZE
+            }))
+        );
+        assert_eq!(
+            parse("gen --day 5 --seed 42 --size 3"),
+            Ok(Command::Gen(GenOptions {
+                day: 5,
+                seed: 42,
+                size: 3
+            }))
+        );
+        assert!(parse("gen").is_err());
+        assert!(parse("gen --day 42").is_err());
+        assert!(parse("gen --day 5 --seed -1").is_err());
+    }
+}
@@ -0,0 +1,95 @@
+use common::{
+    generate::{Generate, Random},
+    parse::ParseError,
+    solution::{self, Answer, Solution},
+};
+
+/// Object-safe view of a parsed [`Solution`], so that every day can be stored in the same table.
+pub trait Solved {
+    fn part(&self, part: u8) -> Answer;
+}
+
+impl<S: Solution> Solved for S {
+    fn part(&self, part: u8) -> Answer {
+        match part {
+            1 => self.part1(),
+            2 => self.part2(),
+            _ => unreachable!(),
+        }
+    }
+}
+
+pub struct Day {
+    pub number: u8,
+    pub parse: fn(&str) -> Result<Box<dyn Solved>, ParseError>,
+    pub generate: fn(&mut Random, usize) -> String,
+}
+
+fn parse<S: Solution + 'static>(input: &str) -> Result<Box<dyn Solved>, ParseError> {
+    let solution = solution::parse::<S>(input)?;
+    Ok(Box::new(solution))
+}
+
+macro_rules! day {
+    ($solution:ty) => {
+        Day {
+            number: <$solution>::DAY,
+            parse: parse::<$solution>,
+            generate: <$solution as Generate>::generate,
+        }
+    };
+}
+
+pub const DAYS: [Day; 25] = [
+    day!(day_01::CalibrationDocument),
+    day!(day_02::Games),
+    day!(day_03::EngineParts),
+    day!(day_04::Cards),
+    day!(day_05::Almanach),
+    day!(day_06::Races),
+    day!(day_07::CamelCards),
+    day!(day_08::NavigationMap),
+    day!(day_09::Histories),
+    day!(day_10::PipeMap),
+    day!(day_11::Observations),
+    day!(day_12::ConditionRecords),
+    day!(day_13::Patterns),
+    day!(day_14::Platform),
+    day!(day_15::InitializationSequence),
+    day!(day_16::Contraption),
+    day!(day_17::City),
+    day!(day_18::DigPlan),
+    day!(day_19::System),
+    day!(day_20::Network),
+    day!(day_21::Garden),
+    day!(day_22::Stack),
+    day!(day_23::HikingMap),
+    day!(day_24::Hailstorm),
+    day!(day_25::Wiring),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use common::generate::Random;
+
+    use crate::days::DAYS;
+
+    #[test]
+    fn generated_inputs() {
+        for day in DAYS.iter() {
+            for seed in 0..3 {
+                let input = (day.generate)(&mut Random::new(seed), 4);
+                assert_eq!(input, (day.generate)(&mut Random::new(seed), 4));
+                let solution = (day.parse)(&input).unwrap_or_else(|error| {
+                    panic!("day {:02}, seed {}: {}\n{}", day.number, seed, error, input)
+                });
+                solution.part(1);
+                solution.part(2);
+            }
+        }
+    }
+}
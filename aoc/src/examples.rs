@@ -0,0 +1,126 @@
+use common::parse::{self, ParseError};
+
+/// An example stored in `fixtures/`, with the answer expected for one of its parts.
+#[derive(Debug, PartialEq)]
+pub struct Example {
+    pub fixture: String,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+}
+
+/// The registry of the examples, one per line:
+///
+/// ```text
+/// # comments and blank lines are ignored
+/// day-08-test2 part 1: 6
+/// ```
+#[derive(Debug, PartialEq, Default)]
+pub struct Examples {
+    pub examples: Vec<Example>,
+}
+
+impl TryFrom<&str> for Examples {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut examples = vec![];
+        for (index, line) in input.lines().enumerate() {
+            let line = line.trim_end();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let example = parse_line(line).map_err(|error| error.on_line(index))?;
+            examples.push(example);
+        }
+        Ok(Self { examples })
+    }
+}
+
+fn parse_line(line: &str) -> Result<Example, ParseError> {
+    let (example, answer) = line
+        .split_once(": ")
+        .ok_or_else(|| ParseError::after(line, "`: <answer>`"))?;
+    let (fixture, part) = example
+        .split_once(" part ")
+        .ok_or_else(|| ParseError::at(line, example, "`<fixture> part <PART>`"))?;
+    let day = fixture
+        .strip_prefix("day-")
+        .and_then(|name| name.split('-').next())
+        .ok_or_else(|| ParseError::at(line, fixture, "a fixture named `day-<DAY>-...`"))?;
+    let day = parse::number(line, day)?;
+    let part_number = parse::number(line, part)?;
+    if !(1..=2).contains(&part_number) {
+        return Err(ParseError::at(line, part, "part 1 or 2"));
+    }
+    Ok(Example {
+        fixture: fixture.to_string(),
+        day,
+        part: part_number,
+        answer: answer.trim().to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::{
+        days,
+        examples::{Example, Examples},
+    };
+
+    #[test]
+    fn parse() {
+        let examples = Examples::try_from("# day 8\n\nday-08-test2 part 1: 6\n").unwrap();
+        assert_eq!(
+            examples.examples,
+            vec![Example {
+                fixture: "day-08-test2".to_string(),
+                day: 8,
+                part: 1,
+                answer: "6".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn invalid_examples() {
+        let error = Examples::try_from("day-08-test part 1: 2\nday-08-test part 3: 6")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (2, 18));
+        let error = Examples::try_from("day-08-test part 1 2").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 21));
+        let error = Examples::try_from("test-08 part 1: 2").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 1));
+        let error = Examples::try_from("day-x-test part 1: 2").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 5));
+    }
+
+    #[test]
+    fn every_example() {
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("../fixtures");
+        let registry = std::fs::read_to_string(fixtures.join("examples")).unwrap();
+        let examples = Examples::try_from(registry.as_str()).unwrap();
+        let failures: Vec<String> = examples
+            .examples
+            .iter()
+            .filter_map(|example| {
+                let day = days::find(example.day).expect("the examples are for existing days");
+                let input = std::fs::read_to_string(fixtures.join(&example.fixture)).unwrap();
+                let answer = match (day.parse)(&input) {
+                    Ok(solution) => solution.part(example.part).to_string(),
+                    Err(error) => error.to_string(),
+                };
+                (answer != example.answer).then(|| {
+                    format!(
+                        "{} part {}: expected {} but got {}",
+                        example.fixture, example.part, example.answer, answer
+                    )
+                })
+            })
+            .collect();
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
@@ -0,0 +1,116 @@
+use std::{fmt::Display, time::Duration};
+
+use common::solution::Answer;
+
+/// The result of a part in `--format json`, printed as a JSON object on a single line.
+///
+/// Integer answers are JSON numbers and text answers JSON strings, `type` telling them apart.
+/// Durations are in nanoseconds. Fields that do not apply, like the answer of a part whose
+/// input could not be parsed, are `null`.
+#[derive(Debug, Default)]
+pub struct PartRecord<'a> {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<&'a Answer>,
+    pub parse_time: Option<Duration>,
+    pub solve_time: Option<Duration>,
+    pub error: Option<&'a str>,
+}
+
+impl Display for PartRecord<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (answer, kind) = match self.answer {
+            Some(Answer::Unsigned(value)) => (value.to_string(), string("unsigned")),
+            Some(Answer::Signed(value)) => (value.to_string(), string("signed")),
+            Some(Answer::Text(value)) => (string(value), string("text")),
+            None => (NULL.to_string(), NULL.to_string()),
+        };
+        let nanos = |duration: Option<Duration>| {
+            duration.map_or(NULL.to_string(), |duration| duration.as_nanos().to_string())
+        };
+        write!(
+            f,
+            "{{\"day\":{},\"part\":{},\"answer\":{},\"type\":{},\"parse_ns\":{},\"solve_ns\":{},\"error\":{}}}",
+            self.day,
+            self.part,
+            answer,
+            kind,
+            nanos(self.parse_time),
+            nanos(self.solve_time),
+            self.error.map_or(NULL.to_string(), string)
+        )
+    }
+}
+
+const NULL: &str = "null";
+
+/// A JSON string literal holding `value`.
+pub fn string(value: &str) -> String {
+    let mut result = String::from('"');
+    for c in value.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if c.is_control() => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use common::solution::Answer;
+
+    use crate::json::{self, PartRecord};
+
+    #[test]
+    fn string() {
+        assert_eq!(json::string("EZPZ"), "\"EZPZ\"");
+        assert_eq!(
+            json::string("line 1, \"x\"\n\\\u{1}"),
+            "\"line 1, \\\"x\\\"\\n\\\\\\u0001\""
+        );
+    }
+
+    #[test]
+    fn records() {
+        let answer = Answer::Unsigned(35);
+        let record = PartRecord {
+            day: 5,
+            part: 1,
+            answer: Some(&answer),
+            parse_time: Some(Duration::from_micros(120)),
+            solve_time: Some(Duration::from_nanos(5560)),
+            error: None,
+        };
+        assert_eq!(
+            record.to_string(),
+            "{\"day\":5,\"part\":1,\"answer\":35,\"type\":\"unsigned\",\"parse_ns\":120000,\"solve_ns\":5560,\"error\":null}"
+        );
+        let answer = Answer::Text("EZPZ".to_string());
+        let record = PartRecord {
+            answer: Some(&answer),
+            ..PartRecord::default()
+        };
+        assert!(record
+            .to_string()
+            .contains("\"answer\":\"EZPZ\",\"type\":\"text\""));
+        let record = PartRecord {
+            day: 2,
+            part: 2,
+            error: Some("day 02, line 1, column 9: expected a number"),
+            ..PartRecord::default()
+        };
+        assert_eq!(
+            record.to_string(),
+            "{\"day\":2,\"part\":2,\"answer\":null,\"type\":null,\"parse_ns\":null,\"solve_ns\":null,\"error\":\"day 02, line 1, column 9: expected a number\"}"
+        );
+    }
+}
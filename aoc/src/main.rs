@@ -0,0 +1,239 @@
+use std::{path::PathBuf, process::ExitCode};
+
+use answers::Answers;
+use cli::{BenchOptions, Command, Format, GenOptions, RunOptions, Selection, VerifyOptions};
+use common::{generate::Random, input::Source};
+use json::PartRecord;
+use timing::Stats;
+
+mod answers;
+mod cli;
+mod days;
+#[cfg(test)]
+mod examples;
+mod json;
+mod timing;
+
+fn run(options: &RunOptions) -> ExitCode {
+    let (days, input) = match &options.selection {
+        Selection::All => (days::DAYS.iter().collect(), None),
+        Selection::Day { day, input } => (
+            vec![days::find(*day).expect("the day has been checked")],
+            input.as_deref(),
+        ),
+    };
+
+    let mut exit_code = ExitCode::SUCCESS;
+    for day in days {
+        let solution = Source::from_arg(input, day.number)
+            .read()
+            .map_err(|error| format!("day {:02}: {}", day.number, error))
+            .and_then(|input| match timing::time(|| (day.parse)(&input)) {
+                (Ok(solution), parse_time) => Ok((solution, parse_time)),
+                (Err(error), _) => Err(error.to_string()),
+            });
+        let (solution, parse_time) = match solution {
+            Ok(solution) => solution,
+            Err(error) => {
+                exit_code = ExitCode::FAILURE;
+                match options.format {
+                    Format::Text => eprintln!("{}", error),
+                    Format::Json => {
+                        for part in options.parts() {
+                            let record = PartRecord {
+                                day: day.number,
+                                part,
+                                error: Some(&error),
+                                ..PartRecord::default()
+                            };
+                            println!("{}", record);
+                        }
+                    }
+                }
+                continue;
+            }
+        };
+        if options.time && options.format == Format::Text {
+            println!("day {:02} parse: {:.2?}", day.number, parse_time);
+        }
+        for part in options.parts() {
+            let (answer, solve_time) = timing::time(|| solution.part(part));
+            match options.format {
+                Format::Text if options.time => println!(
+                    "day {:02} part {}: {} ({:.2?})",
+                    day.number, part, answer, solve_time
+                ),
+                Format::Text => println!("day {:02} part {}: {}", day.number, part, answer),
+                Format::Json => {
+                    let record = PartRecord {
+                        day: day.number,
+                        part,
+                        answer: Some(&answer),
+                        parse_time: Some(parse_time),
+                        solve_time: Some(solve_time),
+                        error: None,
+                    };
+                    println!("{}", record);
+                }
+            }
+        }
+    }
+    exit_code
+}
+
+fn verify(options: &VerifyOptions) -> ExitCode {
+    let path = options
+        .answers
+        .as_ref()
+        .map_or_else(answers::default_path, PathBuf::from);
+    let answers = match std::fs::read_to_string(&path) {
+        Ok(answers) => answers,
+        Err(error) => {
+            eprintln!("cannot read answers from {}: {}", path.display(), error);
+            return ExitCode::FAILURE;
+        }
+    };
+    let answers = match Answers::try_from(answers.as_str()) {
+        Ok(answers) => answers,
+        Err(error) => {
+            eprintln!("{}: {}", path.display(), error);
+            return ExitCode::FAILURE;
+        }
+    };
+    let days = match options.day {
+        Some(day) => vec![days::find(day).expect("the day has been checked")],
+        None => days::DAYS.iter().collect(),
+    };
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for day in days {
+        let expected = [answers.get(day.number, 1), answers.get(day.number, 2)];
+        if expected.iter().all(Option::is_none) {
+            for part in 1..=2 {
+                println!("day {:02} part {}: missing", day.number, part);
+            }
+            missing += 2;
+            continue;
+        }
+        // an input that cannot be read or parsed fails every part having an expected answer
+        let solution = Source::from_arg(None, day.number)
+            .read()
+            .map_err(|error| format!("day {:02}: {}", day.number, error))
+            .and_then(|input| (day.parse)(&input).map_err(|error| error.to_string()));
+        for (part, expected) in (1..=2).zip(expected) {
+            let Some(expected) = expected else {
+                println!("day {:02} part {}: missing", day.number, part);
+                missing += 1;
+                continue;
+            };
+            match &solution {
+                Ok(solution) => {
+                    let answer = solution.part(part).to_string();
+                    if answer == expected {
+                        println!("day {:02} part {}: pass", day.number, part);
+                        passed += 1;
+                    } else {
+                        println!(
+                            "day {:02} part {}: fail, expected {} but got {}",
+                            day.number, part, expected, answer
+                        );
+                        failed += 1;
+                    }
+                }
+                Err(error) => {
+                    println!("day {:02} part {}: fail, {}", day.number, part, error);
+                    failed += 1;
+                }
+            }
+        }
+    }
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn bench(options: &BenchOptions) -> ExitCode {
+    let days = match options.day {
+        Some(day) => vec![days::find(day).expect("the day has been checked")],
+        None => days::DAYS.iter().collect(),
+    };
+
+    let mut exit_code = ExitCode::SUCCESS;
+    // one line per day and step, durations in nanoseconds
+    let mut csv = String::from("day,step,runs,min_ns,median_ns,max_ns\n");
+    for day in days {
+        let input = match Source::from_arg(None, day.number).read() {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("day {:02}: {}", day.number, error);
+                exit_code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        let mut samples = vec![];
+        let mut solution = None;
+        for _ in 0..options.runs {
+            let (parsed, parse_time) = timing::time(|| (day.parse)(&input));
+            samples.push(parse_time);
+            solution = Some(parsed);
+        }
+        let solution = match solution.expect("there is at least one run") {
+            Ok(solution) => solution,
+            Err(error) => {
+                eprintln!("{}", error);
+                exit_code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        let mut steps = vec![("parse".to_string(), Stats::new(samples))];
+        for part in 1..=2 {
+            let samples = (0..options.runs)
+                .map(|_| timing::time(|| solution.part(part)).1)
+                .collect();
+            steps.push((format!("part {}", part), Stats::new(samples)));
+        }
+        for (step, stats) in steps {
+            println!("day {:02} {}: {}", day.number, step, stats);
+            csv.push_str(&format!(
+                "{},{},{},{},{},{}\n",
+                day.number,
+                step.replace(' ', ""),
+                options.runs,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.max.as_nanos()
+            ));
+        }
+    }
+
+    let output = options.output.as_deref().unwrap_or("bench.csv");
+    if let Err(error) = std::fs::write(output, csv) {
+        eprintln!("cannot write the benchmark to {}: {}", output, error);
+        exit_code = ExitCode::FAILURE;
+    }
+    exit_code
+}
+
+fn gen(options: &GenOptions) -> ExitCode {
+    let day = days::find(options.day).expect("the day has been checked");
+    let mut random = Random::new(options.seed);
+    print!("{}", (day.generate)(&mut random, options.size));
+    ExitCode::SUCCESS
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match Command::try_from(args.as_slice()) {
+        Ok(Command::Run(options)) => run(&options),
+        Ok(Command::Verify(options)) => verify(&options),
+        Ok(Command::Bench(options)) => bench(&options),
+        Ok(Command::Gen(options)) => gen(&options),
+        Err(error) => {
+            eprintln!("error: {}\n\n{}", error, cli::USAGE);
+            ExitCode::from(2)
+        }
+    }
+}
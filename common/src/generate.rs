@@ -0,0 +1,100 @@
+use std::ops::RangeInclusive;
+
+/// A small pseudo-random number generator (SplitMix64), so that a seed gives the same input on
+/// every platform and with every version of the crate.
+pub struct Random {
+    state: u64,
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in the range, which must not be empty.
+    pub fn number(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "an empty range");
+        let span = (end - start) as u128 + 1;
+        start + (self.next_u64() as u128 % span) as u64
+    }
+
+    pub fn signed(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "an empty range");
+        let span = (end as i128 - start as i128) as u128 + 1;
+        (start as i128 + (self.next_u64() as u128 % span) as i128) as i64
+    }
+
+    pub fn size(&mut self, range: RangeInclusive<usize>) -> usize {
+        let (start, end) = range.into_inner();
+        self.number(start as u64..=end as u64) as usize
+    }
+
+    /// Whether an event with the given chance in percent happens.
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.number(0..=99) < percent
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.size(0..=items.len() - 1)]
+    }
+
+    /// Shuffles the items in place (Fisher–Yates).
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            items.swap(index, self.size(0..=index));
+        }
+    }
+
+    /// A string of `length` characters picked among `alphabet`.
+    pub fn string(&mut self, length: usize, alphabet: &str) -> String {
+        let alphabet: Vec<char> = alphabet.chars().collect();
+        (0..length).map(|_| *self.choose(&alphabet)).collect()
+    }
+}
+
+/// A puzzle whose inputs can be generated, in the exact format its parser expects and with the
+/// properties the puzzle promises. `size` scales the input, its meaning depends on the puzzle.
+pub trait Generate {
+    fn generate(random: &mut Random, size: usize) -> String;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generate::Random;
+
+    #[test]
+    fn same_seed_same_numbers() {
+        let numbers = |seed| {
+            let mut random = Random::new(seed);
+            (0..10).map(|_| random.next_u64()).collect::<Vec<_>>()
+        };
+        assert_eq!(numbers(42), numbers(42));
+        assert_ne!(numbers(42), numbers(43));
+    }
+
+    #[test]
+    fn ranges() {
+        let mut random = Random::new(0);
+        for _ in 0..1000 {
+            assert!((3..=5).contains(&random.number(3..=5)));
+            assert!((-2..=2).contains(&random.signed(-2..=2)));
+        }
+        assert_eq!(random.size(7..=7), 7);
+        // the whole range does not overflow
+        random.number(0..=u64::MAX);
+        let mut items: Vec<usize> = (0..20).collect();
+        random.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+}
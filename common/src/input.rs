@@ -0,0 +1,124 @@
+use std::{
+    fmt::Display,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Where a puzzle input is read from.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    /// Picks the source from a command-line argument: `-` means stdin, any other value is a
+    /// path, and no argument at all falls back to the default location of the day.
+    pub fn from_arg(arg: Option<&str>, day: u8) -> Self {
+        match arg {
+            Some("-") => Source::Stdin,
+            Some(path) => Source::File(PathBuf::from(path)),
+            None => Source::File(default_path(day)),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        let result = match self {
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+            Source::File(path) => std::fs::read_to_string(path),
+        };
+        result.map_err(|error| InputError {
+            source: self.clone(),
+            error,
+        })
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "stdin"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub source: Source,
+    pub error: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "cannot read puzzle input from {}: {}",
+            self.source, self.error
+        )
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// The directory of the puzzle inputs: `input` at the root of the workspace.
+pub fn input_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the common crate is at the root of the workspace")
+        .join("input")
+}
+
+/// The default location of the input of a day: `input/day-XX` at the root of the workspace.
+pub fn default_path(day: u8) -> PathBuf {
+    input_dir().join(format!("day-{:02}", day))
+}
+
+/// Reads the input of a day from the source given as first command-line argument.
+pub fn read_input(day: u8) -> Result<String, InputError> {
+    let arg = std::env::args().nth(1);
+    Source::from_arg(arg.as_deref(), day).read()
+}
+
+/// Same as [`read_input`], but prints the error and exits instead of returning it.
+pub fn read_input_or_exit(day: u8) -> String {
+    read_input(day).unwrap_or_else(|error| {
+        eprintln!("day {:02}: {}", day, error);
+        eprintln!("pass the input path as first argument, or `-` to read it from stdin");
+        std::process::exit(1);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::input::{default_path, Source};
+
+    #[test]
+    fn source_from_arg() {
+        assert_eq!(Source::from_arg(Some("-"), 5), Source::Stdin);
+        assert_eq!(
+            Source::from_arg(Some("my-input"), 5),
+            Source::File(PathBuf::from("my-input"))
+        );
+        assert_eq!(Source::from_arg(None, 5), Source::File(default_path(5)));
+    }
+
+    #[test]
+    fn default_path_is_padded() {
+        assert!(default_path(5).ends_with("input/day-05"));
+    }
+
+    #[test]
+    fn missing_file() {
+        let source = Source::File(PathBuf::from("this/file/does/not/exist"));
+        let error = source.read().unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("cannot read puzzle input from this/file/does/not/exist"));
+    }
+}
@@ -0,0 +1,199 @@
+use std::{fmt::Display, str::FromStr};
+
+/// An error found while parsing a puzzle input.
+///
+/// Lines and columns start at 1. A parser of a fragment of the input reports positions relative
+/// to that fragment, and the caller moves them with [`ParseError::offset`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseError {
+    pub day: Option<u8>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// An error at the given column of the first line of the parsed text.
+    pub fn new(column: usize, expected: impl Into<String>) -> Self {
+        Self {
+            day: None,
+            line: 1,
+            column,
+            expected: expected.into(),
+        }
+    }
+
+    /// An error on `part`, which must be a slice of `text`.
+    pub fn at(text: &str, part: &str, expected: impl Into<String>) -> Self {
+        Self::new(column(text, part), expected)
+    }
+
+    /// An error right after the end of `text`, when something is missing.
+    pub fn after(text: &str, expected: impl Into<String>) -> Self {
+        Self::new(text.len() + 1, expected)
+    }
+
+    /// Moves the error from a fragment to the text the fragment starts in, `lines` lines below
+    /// its first line and `columns` columns after its first column.
+    pub fn offset(mut self, lines: usize, columns: usize) -> Self {
+        if self.line == 1 {
+            self.column += columns;
+        }
+        self.line += lines;
+        self
+    }
+
+    /// Moves the error from a line to the whole input, the line having the given 0-based index.
+    pub fn on_line(self, index: usize) -> Self {
+        self.offset(index, 0)
+    }
+
+    pub fn for_day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {:02}, ", day)?;
+        }
+        write!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The column of `part` in `text`, `part` being a slice of `text`.
+pub fn column(text: &str, part: &str) -> usize {
+    let offset = (part.as_ptr() as usize).wrapping_sub(text.as_ptr() as usize);
+    debug_assert!(offset <= text.len(), "the part is not a slice of the text");
+    offset + 1
+}
+
+/// Parses `part`, a slice of `text`, reporting its column in `text` when it is not a number.
+pub fn number<T: FromStr>(text: &str, part: &str) -> Result<T, ParseError> {
+    part.trim()
+        .parse()
+        .map_err(|_| ParseError::at(text, part.trim(), "a number"))
+}
+
+/// Parses the whitespace-separated numbers of `part`, a slice of `text`.
+pub fn numbers<T: FromStr>(text: &str, part: &str) -> Result<Vec<T>, ParseError> {
+    part.split_whitespace()
+        .map(|number| self::number(text, number))
+        .collect()
+}
+
+/// Parses every line of `input`, moving errors to the line they were found on.
+pub fn lines<T, F>(input: &str, mut parse: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse(line).map_err(|error| error.on_line(index)))
+        .collect()
+}
+
+/// Parses `input` as a rectangle of characters, every line having the width of the first one.
+pub fn grid<T, F>(input: &str, mut parse: F) -> Result<Vec<Vec<T>>, ParseError>
+where
+    F: FnMut(char) -> Result<T, ParseError>,
+{
+    let width = input
+        .lines()
+        .next()
+        .map(|line| line.chars().count())
+        .unwrap_or_default();
+    lines(input, |line| {
+        let length = line.chars().count();
+        if length != width {
+            let expected = format!("a line of {} characters", width);
+            return Err(ParseError::new(length.min(width) + 1, expected));
+        }
+        line.chars()
+            .enumerate()
+            .map(|(index, c)| parse(c).map_err(|error| error.offset(0, index)))
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{self, ParseError};
+
+    #[test]
+    fn column() {
+        let text = "Game 12: 3 blue";
+        let (_, grabs) = text.split_once(": ").unwrap();
+        assert_eq!(parse::column(text, text), 1);
+        assert_eq!(parse::column(text, grabs), 10);
+    }
+
+    #[test]
+    fn number() {
+        let text = "seeds: 79 x4";
+        assert_eq!(parse::number::<usize>(text, &text[7..9]), Ok(79));
+        assert_eq!(
+            parse::number::<usize>(text, &text[10..]),
+            Err(ParseError::new(11, "a number"))
+        );
+    }
+
+    #[test]
+    fn numbers() {
+        let text = "Time:  7 15  x0";
+        assert_eq!(parse::numbers::<usize>(text, &text[5..11]), Ok(vec![7, 15]));
+        assert_eq!(
+            parse::numbers::<usize>(text, &text[5..]),
+            Err(ParseError::new(14, "a number"))
+        );
+    }
+
+    #[test]
+    fn lines() {
+        let error = parse::lines("1\n2\nthree", |line| parse::number::<usize>(line, line))
+            .unwrap_err()
+            .for_day(4);
+        assert_eq!(
+            error.to_string(),
+            "day 04, line 3, column 1: expected a number"
+        );
+    }
+
+    #[test]
+    fn grid() {
+        let digit = |c: char| c.to_digit(10).ok_or(ParseError::new(1, "a digit"));
+        assert_eq!(
+            parse::grid("12\n34", digit),
+            Ok(vec![vec![1, 2], vec![3, 4]])
+        );
+        assert_eq!(
+            parse::grid("12\n3x", digit),
+            Err(ParseError::new(2, "a digit").on_line(1))
+        );
+        assert_eq!(
+            parse::grid("12\n345", digit),
+            Err(ParseError::new(3, "a line of 2 characters").on_line(1))
+        );
+        assert_eq!(
+            parse::grid("é\nab", Ok),
+            Err(ParseError::new(2, "a line of 1 characters").on_line(1))
+        );
+    }
+
+    #[test]
+    fn offset() {
+        let error = ParseError::new(3, "a colour").offset(0, 10).on_line(4);
+        assert_eq!((error.line, error.column), (5, 13));
+        let error = error.offset(2, 10);
+        assert_eq!((error.line, error.column), (7, 13));
+    }
+}
@@ -0,0 +1,112 @@
+use std::fmt::Display;
+
+use crate::parse::ParseError;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Unsigned(value) => write!(f, "{}", value),
+            Answer::Signed(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Unsigned(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Unsigned(value as u64)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Unsigned(value as u64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Signed(value)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        Answer::Signed(value as i64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// A puzzle of a day: the input is parsed once, then both parts are solved from the parsed model.
+pub trait Solution: Sized {
+    const DAY: u8;
+
+    fn parse(input: &str) -> Result<Self, ParseError>;
+
+    fn part1(&self) -> Answer;
+
+    fn part2(&self) -> Answer;
+}
+
+/// Parses the input of a day, the error naming the day on failure.
+pub fn parse<S: Solution>(input: &str) -> Result<S, ParseError> {
+    S::parse(input).map_err(|error| error.for_day(S::DAY))
+}
+
+/// Entry point of the day binaries: reads the input and prints both answers.
+pub fn main<S: Solution>() {
+    let input = crate::input::read_input_or_exit(S::DAY);
+    let solution = match parse::<S>(&input) {
+        Ok(solution) => solution,
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        }
+    };
+    println!("Part 1: {}", solution.part1());
+    println!("Part 2: {}", solution.part2());
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::Answer;
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Answer::from(42_usize), Answer::Unsigned(42));
+        assert_eq!(Answer::from(42_u32), Answer::Unsigned(42));
+        assert_eq!(Answer::from(-42_isize), Answer::Signed(-42));
+        assert_eq!(Answer::from("42"), Answer::Text("42".to_string()));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Answer::Unsigned(42).to_string(), "42");
+        assert_eq!(Answer::Signed(-42).to_string(), "-42");
+        assert_eq!(Answer::Text("EZPZ".to_string()).to_string(), "EZPZ");
+    }
+}
@@ -0,0 +1,499 @@
+use common::{
+    generate::{Generate, Random},
+    parse::ParseError,
+    solution::{Answer, Solution},
+};
+use std::{
+    fmt::Display,
+    io::{self, BufRead},
+};
+
+/// A word standing for a digit in the calibration document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    pub text: String,
+    pub value: usize,
+}
+
+/// A token found in a line, starting at the byte `position`.
+#[derive(Debug, PartialEq)]
+pub struct Match<'a> {
+    pub token: &'a Token,
+    pub position: usize,
+}
+
+/// The table of the tokens to look for in a line. Tokens can overlap, as in `eightwo`, and
+/// any word can stand for a digit: other languages, `zero` or aliases of its own.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Tokens {
+    pub tokens: Vec<Token>,
+}
+
+impl Tokens {
+    /// The digits from `0` to `9`.
+    pub fn digits() -> Self {
+        (0..=9).fold(Self::default(), |tokens, digit| {
+            tokens.with(&digit.to_string(), digit)
+        })
+    }
+
+    /// The digits, and the English words from `one` to `nine`.
+    pub fn english() -> Self {
+        let words = [
+            "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ];
+        words
+            .iter()
+            .zip(1..)
+            .fold(Self::digits(), |tokens, (word, value)| {
+                tokens.with(word, value)
+            })
+    }
+
+    pub fn with(mut self, text: &str, value: usize) -> Self {
+        self.tokens.push(Token {
+            text: text.to_string(),
+            value,
+        });
+        self
+    }
+
+    /// Every token found in the line, overlaps included, by position then in the order of the
+    /// table.
+    pub fn scan<'a>(&'a self, line: &'a str) -> impl Iterator<Item = Match<'a>> + 'a {
+        line.char_indices().flat_map(move |(position, _)| {
+            self.tokens
+                .iter()
+                .filter(move |token| line[position..].starts_with(token.text.as_str()))
+                .map(move |token| Match { token, position })
+        })
+    }
+
+    pub fn first_and_last<'a>(&'a self, line: &'a str) -> Option<(Match<'a>, Match<'a>)> {
+        let first = self.scan(line).next()?;
+        let last = self.scan(line).last()?;
+        Some((first, last))
+    }
+}
+
+/// Which digits count in a line: part 1 reads the digits only, part 2 reads the spelled ones too.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mode {
+    Digits,
+    DigitsAndWords,
+}
+
+impl Mode {
+    pub fn tokens(&self) -> Tokens {
+        match self {
+            Mode::Digits => Tokens::digits(),
+            Mode::DigitsAndWords => Tokens::english(),
+        }
+    }
+}
+
+/// What became of a line of the document: its value, or why it was rejected.
+#[derive(Debug, PartialEq)]
+pub struct LineReport {
+    // starting at 1
+    pub line: usize,
+    // the value of every token found on the line, in order
+    pub digits: Vec<usize>,
+    pub value: Result<usize, ParseError>,
+}
+
+impl LineReport {
+    /// The report of the line with the given 0-based index.
+    pub fn new(index: usize, line: &str, tokens: &Tokens) -> Self {
+        let digits: Vec<usize> = tokens.scan(line).map(|found| found.token.value).collect();
+        let value = match (digits.first(), digits.last()) {
+            (Some(first), Some(last)) => Ok(first * 10 + last),
+            _ => Err(ParseError::new(1, "a digit on the line").on_line(index)),
+        };
+        Self {
+            line: index + 1,
+            digits,
+            value,
+        }
+    }
+}
+
+/// Whether a line without any digit rejects the whole document, or is only warned about.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Strictness {
+    Strict,
+    Lenient,
+}
+
+pub struct CalibrationReport {
+    pub lines: Vec<LineReport>,
+}
+
+impl CalibrationReport {
+    /// The reports of the lines that are not blank, read by a lenient [`CalibrationStream`].
+    pub fn new(document: &str, tokens: &Tokens) -> Self {
+        let lines = CalibrationStream::new(document.as_bytes(), tokens, Strictness::Lenient)
+            .map(|report| report.map_err(StreamError::in_memory))
+            .map(|report| report.expect("a lenient stream reports every line"))
+            .collect();
+        Self { lines }
+    }
+
+    /// The errors of the rejected lines.
+    pub fn warnings(&self) -> impl Iterator<Item = &ParseError> {
+        self.lines
+            .iter()
+            .filter_map(|line| line.value.as_ref().err())
+    }
+
+    /// The calibration value of the document: in strict mode, the first rejected line is an
+    /// error, in lenient mode, it is skipped.
+    pub fn calibration_value(
+        &self,
+        strictness: Strictness,
+    ) -> Result<CalibrationValue, ParseError> {
+        if let (Strictness::Strict, Some(error)) = (strictness, self.warnings().next()) {
+            return Err(error.clone());
+        }
+        let values: Vec<usize> = self
+            .lines
+            .iter()
+            .filter_map(|line| line.value.as_ref().ok().copied())
+            .collect();
+        Ok(CalibrationValue {
+            value: values.iter().sum(),
+            values,
+        })
+    }
+}
+
+pub struct CalibrationValue {
+    pub value: usize,
+    // the value of each line
+    pub values: Vec<usize>,
+}
+
+impl CalibrationValue {
+    /// The calibration value of a document read by a strict [`CalibrationStream`].
+    pub fn new(value: &str, tokens: &Tokens) -> Result<Self, ParseError> {
+        let values = CalibrationStream::new(value.as_bytes(), tokens, Strictness::Strict)
+            .map(|report| report.map_err(StreamError::in_memory)?.value)
+            .collect::<Result<Vec<usize>, _>>()?;
+        Ok(Self {
+            value: values.iter().sum(),
+            values,
+        })
+    }
+
+    pub fn with_mode(value: &str, mode: Mode) -> Result<Self, ParseError> {
+        CalibrationValue::new(value, &mode.tokens())
+    }
+}
+
+/// Why a streamed document has no calibration value.
+#[derive(Debug)]
+pub enum StreamError {
+    Read(io::Error),
+    Parse(ParseError),
+}
+
+impl Display for StreamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StreamError::Read(error) => write!(f, "cannot read the document: {}", error),
+            StreamError::Parse(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {}
+
+impl StreamError {
+    // a document already in memory is valid UTF-8, reading it cannot fail
+    fn in_memory(self) -> ParseError {
+        match self {
+            StreamError::Read(error) => unreachable!("a string is read whole: {}", error),
+            StreamError::Parse(error) => error,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct RunningTotal {
+    pub total: usize,
+    // blank and rejected lines included
+    pub lines: usize,
+    pub rejected: usize,
+}
+
+/// The calibration of a document read line by line, in constant memory whatever its size. It
+/// iterates over the reports of the lines that are not blank; in strict mode, it stops after the
+/// first line without any digit, which is an error.
+pub struct CalibrationStream<'a, R> {
+    reader: R,
+    tokens: &'a Tokens,
+    strictness: Strictness,
+    // the line being read, reused for every line
+    buffer: String,
+    running_total: RunningTotal,
+    done: bool,
+}
+
+impl<'a, R: BufRead> CalibrationStream<'a, R> {
+    pub fn new(reader: R, tokens: &'a Tokens, strictness: Strictness) -> Self {
+        Self {
+            reader,
+            tokens,
+            strictness,
+            buffer: String::new(),
+            running_total: RunningTotal::default(),
+            done: false,
+        }
+    }
+
+    /// The total of the lines read so far.
+    pub fn running_total(&self) -> RunningTotal {
+        self.running_total
+    }
+
+    /// Reads the rest of the document.
+    pub fn total(mut self) -> Result<RunningTotal, StreamError> {
+        for report in self.by_ref() {
+            report?;
+        }
+        Ok(self.running_total)
+    }
+}
+
+impl<R: BufRead> Iterator for CalibrationStream<'_, R> {
+    type Item = Result<LineReport, StreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            self.buffer.clear();
+            match self.reader.read_line(&mut self.buffer) {
+                Ok(0) => self.done = true,
+                Ok(_) => {
+                    let index = self.running_total.lines;
+                    self.running_total.lines += 1;
+                    let line = self.buffer.strip_suffix('\n').unwrap_or(&self.buffer);
+                    let line = line.strip_suffix('\r').unwrap_or(line);
+                    if line.trim().is_empty() {
+                        continue;
+                    }
+                    let report = LineReport::new(index, line, self.tokens);
+                    match (&report.value, self.strictness) {
+                        (Ok(value), _) => self.running_total.total += value,
+                        (Err(error), Strictness::Strict) => {
+                            self.done = true;
+                            return Some(Err(StreamError::Parse(error.clone())));
+                        }
+                        (Err(_), Strictness::Lenient) => self.running_total.rejected += 1,
+                    }
+                    return Some(Ok(report));
+                }
+                Err(error) => {
+                    self.done = true;
+                    return Some(Err(StreamError::Read(error)));
+                }
+            }
+        }
+        None
+    }
+}
+
+impl TryFrom<String> for CalibrationValue {
+    type Error = ParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        CalibrationValue::with_mode(&value, Mode::DigitsAndWords)
+    }
+}
+
+pub struct CalibrationDocument {
+    pub document: String,
+}
+
+impl Solution for CalibrationDocument {
+    const DAY: u8 = 1;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        // every line needs a digit, spelled or not; the example of part 2 has lines with spelled
+        // digits only, they do not count in part 1
+        CalibrationValue::with_mode(input, Mode::DigitsAndWords)?;
+        Ok(Self {
+            document: input.to_string(),
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        CalibrationReport::new(&self.document, &Mode::Digits.tokens())
+            .calibration_value(Strictness::Lenient)
+            .expect("a lenient calibration always has a value")
+            .value
+            .into()
+    }
+
+    fn part2(&self) -> Answer {
+        CalibrationValue::with_mode(&self.document, Mode::DigitsAndWords)
+            .expect("the document has been checked while parsing")
+            .value
+            .into()
+    }
+}
+
+/// Lines of letters with digits in between, written or spelled out; every line has one at
+/// least. `size` is the number of lines.
+impl Generate for CalibrationDocument {
+    fn generate(random: &mut Random, size: usize) -> String {
+        const SPELLED: [&str; 9] = [
+            "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ];
+        let mut input = String::new();
+        for _ in 0..size.max(1) {
+            for _ in 0..random.size(1..=4) {
+                let length = random.size(0..=4);
+                input.push_str(&random.string(length, "abcdefghijklmnopqrstuvwxyz"));
+                if random.chance(50) {
+                    let spelled = *random.choose(&SPELLED);
+                    input.push_str(spelled);
+                } else {
+                    input.push_str(&random.number(1..=9).to_string());
+                }
+            }
+            let length = random.size(0..=4);
+            input.push_str(&random.string(length, "abcdefghijklmnopqrstuvwxyz"));
+            input.push('\n');
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::generate::{Generate, Random};
+    use std::io::BufReader;
+
+    use crate::{
+        CalibrationDocument, CalibrationReport, CalibrationStream, CalibrationValue, Mode,
+        RunningTotal, StreamError, Strictness, Tokens,
+    };
+
+    #[test]
+    fn modes() {
+        let input = include_str!("../../fixtures/day-01-test");
+        let result = CalibrationValue::with_mode(input, Mode::Digits).unwrap();
+        assert_eq!(result.values, vec![12, 38, 15, 77]);
+        let input = include_str!("../../fixtures/day-01-test2");
+        let result = CalibrationValue::with_mode(input, Mode::DigitsAndWords).unwrap();
+        assert_eq!(result.values, vec![29, 83, 13, 24, 42, 14, 76]);
+        // the digits alone miss the lines where all of them are spelled
+        let error = CalibrationValue::with_mode(input, Mode::Digits)
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    fn scan_overlapping_tokens() {
+        let tokens = Tokens::english();
+        let matches: Vec<(&str, usize, usize)> = tokens
+            .scan("xeightwo3ne")
+            .map(|found| (found.token.text.as_str(), found.token.value, found.position))
+            .collect();
+        assert_eq!(matches, vec![("eight", 8, 1), ("two", 2, 5), ("3", 3, 8)]);
+        let (first, last) = tokens.first_and_last("xeightwo3ne").unwrap();
+        assert_eq!((first.position, last.position), (1, 8));
+        assert!(tokens.first_and_last("abc").is_none());
+    }
+
+    #[test]
+    fn custom_tokens() {
+        let tokens = Tokens::digits()
+            .with("zero", 0)
+            .with("un", 1)
+            .with("deux", 2)
+            .with("trois", 3)
+            .with("uno", 1);
+        let values: Vec<usize> = tokens
+            .scan("zerounotroisé2")
+            .map(|found| found.token.value)
+            .collect();
+        assert_eq!(values, vec![0, 1, 1, 3, 2]);
+        let value = CalibrationValue::new("troisxunzero\nzerdeux", &tokens).unwrap();
+        assert_eq!(value.value, 30 + 22);
+        // without the words, only the digits count
+        let value = CalibrationValue::new("two1nine3", &Tokens::digits()).unwrap();
+        assert_eq!(value.value, 13);
+    }
+
+    #[test]
+    fn report() {
+        let input = "1abc2\r\npqr\r\n\r\ntwo3\r\n\r\n";
+        let report = CalibrationReport::new(input, &Mode::Digits.tokens());
+        let lines: Vec<(usize, Vec<usize>, Option<usize>)> = report
+            .lines
+            .iter()
+            .map(|line| (line.line, line.digits.clone(), line.value.clone().ok()))
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                (1, vec![1, 2], Some(12)),
+                (2, vec![], None),
+                (4, vec![3], Some(33))
+            ]
+        );
+        let warnings: Vec<(usize, usize)> = report
+            .warnings()
+            .map(|warning| (warning.line, warning.column))
+            .collect();
+        assert_eq!(warnings, vec![(2, 1)]);
+        let error = report.calibration_value(Strictness::Strict).err().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+        let value = report.calibration_value(Strictness::Lenient).unwrap();
+        assert_eq!((value.value, value.values), (45, vec![12, 33]));
+    }
+
+    #[test]
+    fn stream() {
+        let tokens = Mode::DigitsAndWords.tokens();
+        let input = CalibrationDocument::generate(&mut Random::new(1), 200);
+        // a small buffer to read lines over several refills
+        let reader = BufReader::with_capacity(16, input.as_bytes());
+        let total = CalibrationStream::new(reader, &tokens, Strictness::Strict)
+            .total()
+            .unwrap();
+        let value = CalibrationValue::with_mode(&input, Mode::DigitsAndWords).unwrap();
+        assert_eq!((total.total, total.lines), (value.value, 200));
+
+        let input = "1abc2\r\npqr\n\ntwo3";
+        let tokens = Mode::Digits.tokens();
+        let mut stream = CalibrationStream::new(input.as_bytes(), &tokens, Strictness::Lenient);
+        let lines: Vec<usize> = stream.by_ref().map(|report| report.unwrap().line).collect();
+        assert_eq!(lines, vec![1, 2, 4]);
+        let expected = RunningTotal {
+            total: 45,
+            lines: 4,
+            rejected: 1,
+        };
+        assert_eq!(stream.running_total(), expected);
+
+        let stream = CalibrationStream::new(input.as_bytes(), &tokens, Strictness::Strict);
+        match stream.total() {
+            Err(StreamError::Parse(error)) => assert_eq!((error.line, error.column), (2, 1)),
+            _ => panic!("the second line has no digit"),
+        }
+        let stream = CalibrationStream::new(&b"12\n\xff3\n"[..], &tokens, Strictness::Strict);
+        assert!(matches!(stream.total(), Err(StreamError::Read(_))));
+    }
+
+    #[test]
+    fn line_without_digit() {
+        let input = "1abc2\npqrstuvwx\n".to_string();
+        let error = CalibrationValue::try_from(input).err().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+        let value = CalibrationValue::with_mode("1abc2\r\n\r\ntwo3\r\n", Mode::Digits).unwrap();
+        assert_eq!(value.values, vec![12, 33]);
+    }
+}
@@ -0,0 +1,230 @@
+use common::{
+    generate::{Generate, Random},
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+use std::{collections::BTreeMap, ops::Deref};
+
+/// The cubes in the bag, by colour: any colour it does not list has no cubes.
+#[derive(Debug, Default, PartialEq)]
+pub struct Bag {
+    pub cubes: BTreeMap<String, usize>,
+}
+
+impl Bag {
+    pub fn count(&self, colour: &str) -> usize {
+        self.cubes.get(colour).copied().unwrap_or_default()
+    }
+}
+
+impl<const N: usize> From<[(&str, usize); N]> for Bag {
+    fn from(cubes: [(&str, usize); N]) -> Self {
+        Self {
+            cubes: cubes
+                .into_iter()
+                .map(|(colour, count)| (colour.to_string(), count))
+                .collect(),
+        }
+    }
+}
+
+pub trait Possible {
+    fn is_possible(&self, bag: &Bag) -> bool;
+}
+
+/// The cubes shown in a grab, by colour.
+#[derive(Debug, Default, PartialEq)]
+pub struct CubeGrab {
+    pub cubes: BTreeMap<String, usize>,
+}
+
+impl Possible for CubeGrab {
+    fn is_possible(&self, bag: &Bag) -> bool {
+        self.iter()
+            .all(|(colour, &count)| count <= bag.count(colour))
+    }
+}
+
+impl Deref for CubeGrab {
+    type Target = BTreeMap<String, usize>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.cubes
+    }
+}
+
+impl TryFrom<&str> for CubeGrab {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut cubes = BTreeMap::new();
+        for grab in input.split(",") {
+            let (value, colour) = grab
+                .trim()
+                .split_once(" ")
+                .ok_or_else(|| ParseError::at(input, grab, "a number of cubes and a colour"))?;
+            let value: usize = parse::number(input, value)?;
+            if colour.is_empty() || !colour.chars().all(char::is_alphabetic) {
+                return Err(ParseError::at(input, colour, "a colour"));
+            }
+            *cubes.entry(colour.to_string()).or_default() += value;
+        }
+        Ok(Self { cubes })
+    }
+}
+
+pub struct Game {
+    pub id: usize,
+    pub cube_grabs: Vec<CubeGrab>,
+}
+
+impl Possible for Game {
+    fn is_possible(&self, bag: &Bag) -> bool {
+        self.cube_grabs
+            .iter()
+            .all(|cube_grab| cube_grab.is_possible(bag))
+    }
+}
+
+impl TryFrom<&str> for Game {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let (id_input, grabs_input) = input
+            .split_once(":")
+            .ok_or_else(|| ParseError::after(input, "`:`"))?;
+        let id = id_input
+            .strip_prefix("Game ")
+            .ok_or_else(|| ParseError::new(1, "`Game `"))?;
+        let id = parse::number(input, id)?;
+        let cube_grabs = grabs_input
+            .split(";")
+            .map(|grab_input| {
+                CubeGrab::try_from(grab_input)
+                    .map_err(|error| error.offset(0, parse::column(input, grab_input) - 1))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { id, cube_grabs })
+    }
+}
+
+impl Game {
+    /// The fewest cubes of each colour seen in the game that make it possible.
+    pub fn minimal_bag(&self) -> Bag {
+        let mut cubes: BTreeMap<String, usize> = BTreeMap::new();
+        for (colour, &count) in self.cube_grabs.iter().flat_map(|grab| grab.iter()) {
+            let most = cubes.entry(colour.clone()).or_default();
+            *most = count.max(*most);
+        }
+        Bag { cubes }
+    }
+
+    pub fn power(&self) -> usize {
+        self.minimal_bag().cubes.values().product()
+    }
+}
+
+pub struct Games {
+    pub games: Vec<Game>,
+}
+
+impl TryFrom<&str> for Games {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        Ok(Self {
+            games: parse::lines(input, |line| Game::try_from(line))?,
+        })
+    }
+}
+
+impl Deref for Games {
+    type Target = Vec<Game>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.games
+    }
+}
+
+impl Games {
+    pub fn sum_of_possible_ids(&self, bag: &Bag) -> usize {
+        self.iter()
+            .filter(|game| game.is_possible(bag))
+            .map(|game| game.id)
+            .sum()
+    }
+
+    pub fn sum_of_powers(&self) -> usize {
+        self.iter().map(|game| game.power()).sum()
+    }
+}
+
+impl Solution for Games {
+    const DAY: u8 = 2;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Games::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        let bag = Bag::from([("red", 12), ("green", 13), ("blue", 14)]);
+        self.sum_of_possible_ids(&bag).into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.sum_of_powers().into()
+    }
+}
+
+/// Games of a few grabs, each showing some of the colours. `size` is the number of games.
+impl Generate for Games {
+    fn generate(random: &mut Random, size: usize) -> String {
+        let mut input = String::new();
+        for id in 1..=size.max(1) {
+            let grabs: Vec<String> = (0..random.size(1..=6))
+                .map(|_| {
+                    let mut colours = ["red", "green", "blue"];
+                    random.shuffle(&mut colours);
+                    let shown = random.size(1..=3);
+                    colours[..shown]
+                        .iter()
+                        .map(|colour| format!("{} {}", random.number(1..=16), colour))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                })
+                .collect();
+            input.push_str(&format!("Game {}: {}\n", id, grabs.join("; ")));
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::parse::ParseError;
+
+    use crate::{Bag, Game, Games, Possible};
+
+    #[test]
+    fn other_colours() {
+        let game = Game::try_from("Game 7: 2 yellow, 1 red; 3 purple, 1 yellow, 2 red").unwrap();
+        assert_eq!(
+            game.minimal_bag(),
+            Bag::from([("purple", 3), ("red", 2), ("yellow", 2)])
+        );
+        assert_eq!(game.power(), 12);
+        assert!(game.is_possible(&Bag::from([("purple", 3), ("red", 2), ("yellow", 5)])));
+        // no yellow cubes in the bag
+        assert!(!game.is_possible(&Bag::from([("purple", 3), ("red", 2)])));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = Games::try_from("Game 1: 3 blue\nGame 2: 1 red, 2 pur-ple").err();
+        assert_eq!(error, Some(ParseError::new(18, "a colour").on_line(1)));
+        let error = Games::try_from("Game x: 3 blue").err();
+        assert_eq!(error, Some(ParseError::new(6, "a number")));
+        let error = Games::try_from("Game 1 3 blue").err();
+        assert_eq!(error, Some(ParseError::new(14, "`:`")));
+    }
+}
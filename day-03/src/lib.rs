@@ -0,0 +1,384 @@
+use common::{
+    generate::{Generate, Random},
+    parse::ParseError,
+    solution::{Answer, Solution},
+};
+use grid::{Grid, Position};
+use std::{
+    collections::BTreeSet,
+    ops::{Deref, DerefMut},
+};
+
+#[derive(Debug, PartialEq)]
+pub struct Number {
+    pub value: usize,
+    pub is_part: bool,
+    pub interval: (usize, usize),
+}
+
+impl Number {
+    fn covers(&self, x: usize) -> bool {
+        (self.interval.0..=self.interval.1).contains(&x)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Numbers {
+    pub numbers: Vec<Number>,
+}
+
+impl Deref for Numbers {
+    type Target = Vec<Number>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.numbers
+    }
+}
+
+impl DerefMut for Numbers {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.numbers
+    }
+}
+
+impl From<&str> for Numbers {
+    fn from(input: &str) -> Self {
+        let mut numbers = vec![];
+        let mut interv_end: Option<usize> = None;
+        let mut multiplier = 1;
+        let mut current_number = 0;
+        input.char_indices().rev().for_each(|(index, char)| {
+            match char.to_string().parse::<usize>() {
+                Ok(number) => {
+                    current_number += multiplier * number;
+                    multiplier *= 10;
+                    if interv_end.is_none() {
+                        interv_end = Some(index);
+                    }
+                }
+                Err(_) => {
+                    if let Some(int_end) = interv_end {
+                        numbers.push(Number {
+                            value: current_number,
+                            is_part: false,
+                            interval: (index + 1, int_end),
+                        });
+                    }
+                    interv_end = None;
+                    multiplier = 1;
+                    current_number = 0;
+                }
+            }
+        });
+        if let Some(int_end) = interv_end {
+            numbers.push(Number {
+                value: current_number,
+                is_part: false,
+                interval: (0, int_end),
+            });
+        }
+        numbers.reverse();
+        Self { numbers }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Symbol {
+    pub coords: Position,
+    pub is_gear: bool,
+    pub gear_ratio: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct EngineParts {
+    pub parts: Vec<Numbers>,
+    pub symbols: Vec<Symbol>,
+}
+
+impl TryFrom<&str> for EngineParts {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let schematic = Grid::try_from(value)?;
+        let parts = value.lines().map(|line| line.into()).collect();
+        let symbols = schematic
+            .iter()
+            .filter(|(_, c)| is_symbol(c))
+            .map(|(coords, &c)| Symbol {
+                coords,
+                is_gear: c == '*',
+                gear_ratio: 0,
+            })
+            .collect();
+        let mut result = Self { parts, symbols };
+        result.mark_parts(&schematic);
+        Ok(result)
+    }
+}
+
+impl Deref for EngineParts {
+    type Target = Vec<Numbers>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.parts
+    }
+}
+
+impl DerefMut for EngineParts {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.parts
+    }
+}
+
+impl EngineParts {
+    fn mark_parts(&mut self, schematic: &Grid<char>) {
+        self.symbols.iter_mut().for_each(|symbol| {
+            let digits: Vec<Position> = schematic
+                .neighbours8(symbol.coords)
+                .filter(|&position| schematic[position].is_ascii_digit())
+                .collect();
+            // going through the numbers line by line counts each of them once
+            let lines: BTreeSet<usize> = digits.iter().map(|&(_, y)| y).collect();
+            let mut ratio = 1;
+            let mut number_of_parts = 0;
+            for line_index in lines {
+                self.parts[line_index]
+                    .iter_mut()
+                    .filter(|part| {
+                        digits
+                            .iter()
+                            .any(|&(x, y)| y == line_index && part.covers(x))
+                    })
+                    .for_each(|part| {
+                        part.is_part = true;
+                        number_of_parts += 1;
+                        ratio *= part.value;
+                    })
+            }
+            if symbol.is_gear && number_of_parts == 2 {
+                symbol.gear_ratio = ratio;
+            }
+        });
+    }
+
+    pub fn sum_of_parts(&self) -> usize {
+        self.parts
+            .iter()
+            .map(|line| {
+                line.iter()
+                    .filter(|part| part.is_part)
+                    .map(|part| part.value)
+                    .sum::<usize>()
+            })
+            .sum()
+    }
+
+    pub fn sum_of_gear_ratios(&self) -> usize {
+        self.symbols.iter().map(|symbol| symbol.gear_ratio).sum()
+    }
+}
+
+fn is_symbol(c: &char) -> bool {
+    !matches!(c, '0'..='9' | '.')
+}
+
+impl Solution for EngineParts {
+    const DAY: u8 = 3;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        EngineParts::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        self.sum_of_parts().into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.sum_of_gear_ratios().into()
+    }
+}
+
+/// A square schematic of numbers and symbols, many of them gears. `size` is the side of the
+/// square.
+impl Generate for EngineParts {
+    fn generate(random: &mut Random, size: usize) -> String {
+        const SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '@', '=', '%', '&', '-'];
+        let side = size.max(3);
+        let mut input = String::new();
+        for _ in 0..side {
+            let mut line = String::new();
+            while line.len() < side {
+                let after_number = line.ends_with(|c: char| c.is_ascii_digit());
+                if !after_number && random.chance(20) {
+                    let digits = random.size(1..=3.min(side - line.len())) as u32;
+                    let number = random.number(10_u64.pow(digits - 1)..=10_u64.pow(digits) - 1);
+                    line.push_str(&number.to_string());
+                } else if random.chance(15) {
+                    // half of the symbols are gears, to be next to two numbers now and then
+                    let symbol = match random.chance(50) {
+                        true => '*',
+                        false => *random.choose(&SYMBOLS),
+                    };
+                    line.push(symbol);
+                } else {
+                    line.push('.');
+                }
+            }
+            input.push_str(&line);
+            input.push('\n');
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EngineParts;
+    use crate::Number;
+    use crate::Numbers;
+    use crate::Symbol;
+
+    #[test]
+    fn simple_line_number_parse() {
+        let numbers = Numbers::from("467..114..");
+        assert_eq!(
+            numbers,
+            Numbers {
+                numbers: vec![
+                    Number {
+                        value: 467,
+                        is_part: false,
+                        interval: (0, 2)
+                    },
+                    Number {
+                        value: 114,
+                        is_part: false,
+                        interval: (5, 7)
+                    }
+                ]
+            }
+        );
+    }
+
+    #[test]
+    fn file_number_parse() {
+        let input = include_str!("../../fixtures/day-03-test");
+        let numbers = EngineParts::try_from(input).unwrap();
+        let expected_engine_numbers = EngineParts {
+            parts: vec![
+                Numbers {
+                    numbers: vec![
+                        Number {
+                            value: 467,
+                            is_part: true,
+                            interval: (0, 2),
+                        },
+                        Number {
+                            value: 114,
+                            is_part: false,
+                            interval: (5, 7),
+                        },
+                    ],
+                },
+                Numbers { numbers: vec![] },
+                Numbers {
+                    numbers: vec![
+                        Number {
+                            value: 35,
+                            is_part: true,
+                            interval: (2, 3),
+                        },
+                        Number {
+                            value: 633,
+                            is_part: true,
+                            interval: (6, 8),
+                        },
+                    ],
+                },
+                Numbers { numbers: vec![] },
+                Numbers {
+                    numbers: vec![Number {
+                        value: 617,
+                        is_part: true,
+                        interval: (0, 2),
+                    }],
+                },
+                Numbers {
+                    numbers: vec![Number {
+                        value: 58,
+                        is_part: false,
+                        interval: (7, 8),
+                    }],
+                },
+                Numbers {
+                    numbers: vec![Number {
+                        value: 592,
+                        is_part: true,
+                        interval: (2, 4),
+                    }],
+                },
+                Numbers {
+                    numbers: vec![Number {
+                        value: 755,
+                        is_part: true,
+                        interval: (6, 8),
+                    }],
+                },
+                Numbers { numbers: vec![] },
+                Numbers {
+                    numbers: vec![
+                        Number {
+                            value: 664,
+                            is_part: true,
+                            interval: (1, 3),
+                        },
+                        Number {
+                            value: 598,
+                            is_part: true,
+                            interval: (5, 7),
+                        },
+                    ],
+                },
+            ],
+            symbols: vec![
+                Symbol {
+                    coords: (3, 1),
+                    is_gear: true,
+                    gear_ratio: 16345,
+                },
+                Symbol {
+                    coords: (6, 3),
+                    is_gear: false,
+                    gear_ratio: 0,
+                },
+                Symbol {
+                    coords: (3, 4),
+                    is_gear: true,
+                    gear_ratio: 0,
+                },
+                Symbol {
+                    coords: (5, 5),
+                    is_gear: false,
+                    gear_ratio: 0,
+                },
+                Symbol {
+                    coords: (3, 8),
+                    is_gear: false,
+                    gear_ratio: 0,
+                },
+                Symbol {
+                    coords: (5, 8),
+                    is_gear: true,
+                    gear_ratio: 451490,
+                },
+            ],
+        };
+        assert_eq!(numbers, expected_engine_numbers);
+    }
+
+    #[test]
+    fn ragged_lines() {
+        let error = EngineParts::try_from("467..\n..*\n.35..").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 4));
+    }
+}
@@ -0,0 +1,182 @@
+use common::{
+    generate::{Generate, Random},
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+use std::ops::Deref;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Card {
+    pub id: usize,
+    pub winning_numbers: Vec<usize>,
+    pub numbers: Vec<usize>,
+}
+
+impl Card {
+    pub fn get_number_of_wins(&self) -> usize {
+        self.numbers
+            .iter()
+            .filter(|number| self.winning_numbers.contains(number))
+            .count()
+    }
+
+    pub fn get_points(&self) -> usize {
+        match self.get_number_of_wins() {
+            0 => 0,
+            n => {
+                let base: usize = 2;
+                base.pow((n - 1) as u32)
+            }
+        }
+    }
+}
+
+impl TryFrom<&str> for Card {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let (card_id_str, numbers_str) = input
+            .split_once(":")
+            .ok_or_else(|| ParseError::after(input, "`:`"))?;
+        let id = card_id_str
+            .strip_prefix("Card")
+            .ok_or_else(|| ParseError::new(1, "`Card`"))?;
+        let id = parse::number(input, id)?;
+        let (winning_numbers, numbers) = numbers_str
+            .split_once("|")
+            .ok_or_else(|| ParseError::after(input, "`|`"))?;
+        Ok(Card {
+            id,
+            winning_numbers: parse::numbers(input, winning_numbers)?,
+            numbers: parse::numbers(input, numbers)?,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Cards {
+    pub cards: Vec<Card>,
+}
+
+impl Cards {
+    pub fn get_points(&self) -> usize {
+        self.iter().map(|card| card.get_points()).sum()
+    }
+
+    pub fn compute_sum_of_won_cards(&self) -> usize {
+        let mut numbers_of_cards = vec![1; self.cards.len()];
+        self.cards
+            .iter()
+            .enumerate()
+            .for_each(|(card_index, card)| {
+                let number_of_this_card = numbers_of_cards[card_index];
+                let number_of_wins = card.get_number_of_wins();
+                numbers_of_cards[card_index + 1..=card_index + number_of_wins]
+                    .iter_mut()
+                    .for_each(|number_of_won_card| *number_of_won_card += number_of_this_card);
+            });
+        numbers_of_cards.iter().sum()
+    }
+}
+
+impl Deref for Cards {
+    type Target = Vec<Card>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.cards
+    }
+}
+
+impl TryFrom<&str> for Cards {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let cards = parse::lines(input, |line| Card::try_from(line))?;
+        Ok(Cards { cards })
+    }
+}
+
+impl Solution for Cards {
+    const DAY: u8 = 4;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Cards::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        self.get_points().into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.compute_sum_of_won_cards().into()
+    }
+}
+
+/// Scratchcards of 5 winning numbers and 8 numbers, no card winning copies past the end of the
+/// table. `size` is the number of cards.
+impl Generate for Cards {
+    fn generate(random: &mut Random, size: usize) -> String {
+        let count = size.max(1);
+        let mut input = String::new();
+        for index in 0..count {
+            let mut pool: Vec<usize> = (1..=99).collect();
+            random.shuffle(&mut pool);
+            let wins = random.size(0..=5.min(count - 1 - index));
+            let winning_numbers = &pool[..5];
+            let mut numbers: Vec<usize> = winning_numbers[..wins].to_vec();
+            numbers.extend(&pool[5..5 + 8 - wins]);
+            random.shuffle(&mut numbers);
+            let format = |numbers: &[usize]| {
+                numbers
+                    .iter()
+                    .map(|number| format!("{:>2}", number))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            };
+            input.push_str(&format!(
+                "Card {:>3}: {} | {}\n",
+                index + 1,
+                format(winning_numbers),
+                format(&numbers)
+            ));
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::parse::ParseError;
+
+    use crate::Card;
+
+    #[test]
+    fn read_card() {
+        let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
+        let card = Card::try_from(input).unwrap();
+        assert_eq!(
+            card,
+            Card {
+                id: 1,
+                winning_numbers: vec![41, 48, 83, 86, 17],
+                numbers: vec![83, 86, 6, 31, 17, 9, 48, 53]
+            }
+        );
+    }
+
+    #[test]
+    fn read_invalid_card() {
+        let error = Card::try_from("Card 1: 41 4x | 83").err().unwrap();
+        assert_eq!(error, ParseError::new(12, "a number"));
+        let error = Card::try_from("Card 1: 41 48").err().unwrap();
+        assert_eq!(error, ParseError::new(14, "`|`"));
+    }
+
+    #[test]
+    fn get_card_points() {
+        let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
+        let card = Card::try_from(input).unwrap();
+        let points = card.get_points();
+        assert_eq!(points, 8);
+    }
+}
@@ -0,0 +1,418 @@
+use common::{
+    generate::{Generate, Random},
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+use std::ops::{Deref, Range};
+
+#[derive(Debug, PartialEq)]
+pub struct SeedMap {
+    pub destination: Range<usize>,
+    pub source: Range<usize>,
+}
+
+impl TryFrom<&str> for SeedMap {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let numbers: Vec<usize> = parse::numbers(input, input)?;
+        let [destination_start, source_start, length] = numbers[..] else {
+            return Err(ParseError::new(1, "three numbers"));
+        };
+        Ok(SeedMap {
+            destination: destination_start..destination_start + length,
+            source: source_start..source_start + length,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SeedMaps {
+    pub maps: Vec<SeedMap>,
+}
+
+impl SeedMaps {
+    pub fn next_step(&self, step: usize) -> usize {
+        match self.iter().find(|map| map.source.contains(&step)) {
+            Some(map) => {
+                let offset = step - map.source.start;
+                map.destination.start + offset
+            }
+            None => step,
+        }
+    }
+}
+
+impl SeedMaps {
+    /// Parses a section of the almanach: a `<name> map:` header followed by a map per line, up
+    /// to an empty line or the end of the input.
+    fn parse_section<'a>(
+        lines: &mut impl Iterator<Item = (usize, &'a str)>,
+        number_of_lines: usize,
+        name: &str,
+    ) -> Result<Self, ParseError> {
+        let header = format!("{} map:", name);
+        let expected_header = || ParseError::new(1, format!("`{}`", header));
+        // the sections are separated by an empty line
+        match lines.find(|(_, line)| !line.is_empty()) {
+            Some((_, line)) if line == header => {}
+            Some((index, _)) => return Err(expected_header().on_line(index)),
+            None => return Err(expected_header().on_line(number_of_lines)),
+        }
+        let maps = lines
+            .take_while(|(_, line)| !line.is_empty())
+            .map(|(index, line)| SeedMap::try_from(line).map_err(|error| error.on_line(index)))
+            .collect::<Result<_, _>>()?;
+        Ok(SeedMaps { maps })
+    }
+}
+
+impl Deref for SeedMaps {
+    type Target = Vec<SeedMap>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.maps
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Almanach {
+    pub seeds: Vec<usize>,
+    pub seed_ranges: Vec<Range<usize>>,
+    pub seed_to_soil: SeedMaps,
+    pub soil_to_fertilizer: SeedMaps,
+    pub fertilizer_to_water: SeedMaps,
+    pub water_to_light: SeedMaps,
+    pub light_to_temperature: SeedMaps,
+    pub temperature_to_humidity: SeedMaps,
+    pub humidity_to_location: SeedMaps,
+}
+
+impl Almanach {
+    pub fn compute_seed_location(&self, seed: usize) -> usize {
+        let soil = self.seed_to_soil.next_step(seed);
+        let fertilizer = self.soil_to_fertilizer.next_step(soil);
+        let water = self.fertilizer_to_water.next_step(fertilizer);
+        let light = self.water_to_light.next_step(water);
+        let temperature = self.light_to_temperature.next_step(light);
+        let humidity = self.temperature_to_humidity.next_step(temperature);
+        self.humidity_to_location.next_step(humidity)
+    }
+
+    pub fn find_lowest_location(&self) -> usize {
+        self.seeds
+            .iter()
+            .map(|seed| self.compute_seed_location(*seed))
+            .min()
+            .expect("there should be a min location")
+    }
+
+    pub fn find_lowest_location_from_ranges(&self) -> usize {
+        self.seed_ranges
+            .iter()
+            .flat_map(|range| range.clone().map(|seed| self.compute_seed_location(seed)))
+            .min()
+            .expect("there should be a min location")
+    }
+}
+
+impl TryFrom<&str> for Almanach {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let number_of_lines = input.lines().count();
+        let mut lines = input.lines().enumerate();
+        let seeds_line = lines.next().map(|(_, line)| line).unwrap_or_default();
+        let seeds = seeds_line
+            .strip_prefix("seeds:")
+            .ok_or_else(|| ParseError::new(1, "`seeds:`"))?;
+        let seeds: Vec<usize> = parse::numbers(seeds_line, seeds)?;
+        if seeds.is_empty() {
+            return Err(ParseError::after(seeds_line, "a seed"));
+        }
+        // in part 2, the seeds come in pairs of a start and a length
+        if seeds.len() % 2 == 1 {
+            return Err(ParseError::after(
+                seeds_line,
+                "the length of the last seed range",
+            ));
+        }
+        if let Some(length) = seeds_line
+            .split_whitespace()
+            // after `seeds:` and the start of the first range
+            .skip(2)
+            .step_by(2)
+            .find(|length| length.parse() == Ok(0))
+        {
+            return Err(ParseError::at(
+                seeds_line,
+                length,
+                "a range of one seed at least",
+            ));
+        }
+        let seed_ranges = seeds
+            .chunks_exact(2)
+            .map(|chunk| chunk[0]..chunk[0] + chunk[1])
+            .collect();
+        let mut section = |name| SeedMaps::parse_section(&mut lines, number_of_lines, name);
+        Ok(Almanach {
+            seed_to_soil: section("seed-to-soil")?,
+            soil_to_fertilizer: section("soil-to-fertilizer")?,
+            fertilizer_to_water: section("fertilizer-to-water")?,
+            water_to_light: section("water-to-light")?,
+            light_to_temperature: section("light-to-temperature")?,
+            temperature_to_humidity: section("temperature-to-humidity")?,
+            humidity_to_location: section("humidity-to-location")?,
+            seeds,
+            seed_ranges,
+        })
+    }
+}
+
+impl Solution for Almanach {
+    const DAY: u8 = 5;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Almanach::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        self.find_lowest_location().into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.find_lowest_location_from_ranges().into()
+    }
+}
+
+/// An almanach whose maps move parts of the numbers below `100 * size`, with `size` small
+/// ranges of seeds.
+impl Generate for Almanach {
+    fn generate(random: &mut Random, size: usize) -> String {
+        const SECTIONS: [&str; 7] = [
+            "seed-to-soil",
+            "soil-to-fertilizer",
+            "fertilizer-to-water",
+            "water-to-light",
+            "light-to-temperature",
+            "temperature-to-humidity",
+            "humidity-to-location",
+        ];
+        let limit = 100 * size.max(1);
+        let seeds: Vec<String> = (0..size.max(1))
+            .flat_map(|_| [random.size(0..=limit - 1), random.size(1..=20)])
+            .map(|number| number.to_string())
+            .collect();
+        let mut input = format!("seeds: {}\n", seeds.join(" "));
+        for section in SECTIONS {
+            input.push_str(&format!("\n{} map:\n", section));
+            // the sources are consecutive slices of the numbers, some of them left unmapped
+            let mut cuts: Vec<usize> = (0..random.size(1..=size.max(1)))
+                .map(|_| random.size(0..=limit))
+                .chain([0, limit])
+                .collect();
+            cuts.sort();
+            cuts.dedup();
+            for source in cuts.windows(2) {
+                if random.chance(70) {
+                    let destination = random.size(0..=limit);
+                    let length = source[1] - source[0];
+                    input.push_str(&format!("{} {} {}\n", destination, source[0], length));
+                }
+            }
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::parse::ParseError;
+
+    use crate::{Almanach, SeedMap, SeedMaps};
+
+    #[test]
+    fn parse_seed_map() {
+        let input = "50 98 2";
+        let source_map = SeedMap::try_from(input).unwrap();
+        assert_eq!(
+            source_map,
+            SeedMap {
+                destination: 50..52,
+                source: 98..100,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_almanach() {
+        let input = include_str!("../../fixtures/day-05-test");
+        let almanach = Almanach::try_from(input).unwrap();
+        assert_eq!(
+            almanach,
+            Almanach {
+                seeds: vec![79, 14, 55, 13],
+                seed_ranges: vec![79..93, 55..68],
+                seed_to_soil: SeedMaps {
+                    maps: vec![
+                        SeedMap {
+                            destination: 50..52,
+                            source: 98..100
+                        },
+                        SeedMap {
+                            destination: 52..100,
+                            source: 50..98
+                        }
+                    ]
+                },
+                soil_to_fertilizer: SeedMaps {
+                    maps: vec![
+                        SeedMap {
+                            destination: 0..37,
+                            source: 15..52
+                        },
+                        SeedMap {
+                            destination: 37..39,
+                            source: 52..54
+                        },
+                        SeedMap {
+                            destination: 39..54,
+                            source: 0..15
+                        }
+                    ]
+                },
+                fertilizer_to_water: SeedMaps {
+                    maps: vec![
+                        SeedMap {
+                            destination: 49..57,
+                            source: 53..61
+                        },
+                        SeedMap {
+                            destination: 0..42,
+                            source: 11..53
+                        },
+                        SeedMap {
+                            destination: 42..49,
+                            source: 0..7
+                        },
+                        SeedMap {
+                            destination: 57..61,
+                            source: 7..11
+                        }
+                    ]
+                },
+                water_to_light: SeedMaps {
+                    maps: vec![
+                        SeedMap {
+                            destination: 88..95,
+                            source: 18..25
+                        },
+                        SeedMap {
+                            destination: 18..88,
+                            source: 25..95
+                        }
+                    ]
+                },
+                light_to_temperature: SeedMaps {
+                    maps: vec![
+                        SeedMap {
+                            destination: 45..68,
+                            source: 77..100
+                        },
+                        SeedMap {
+                            destination: 81..100,
+                            source: 45..64
+                        },
+                        SeedMap {
+                            destination: 68..81,
+                            source: 64..77
+                        }
+                    ]
+                },
+                temperature_to_humidity: SeedMaps {
+                    maps: vec![
+                        SeedMap {
+                            destination: 0..1,
+                            source: 69..70
+                        },
+                        SeedMap {
+                            destination: 1..70,
+                            source: 0..69
+                        }
+                    ]
+                },
+                humidity_to_location: SeedMaps {
+                    maps: vec![
+                        SeedMap {
+                            destination: 60..97,
+                            source: 56..93
+                        },
+                        SeedMap {
+                            destination: 56..60,
+                            source: 93..97
+                        }
+                    ]
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn parse_invalid_almanach() {
+        let error = Almanach::try_from("seeds: 79 14\n\nseed-to-soil map:\n50 98 x\n").err();
+        assert_eq!(error, Some(ParseError::new(7, "a number").on_line(3)));
+        let error = Almanach::try_from("seeds: 79 14\n\nseed-to-soil map:\n50 98\n").err();
+        assert_eq!(error, Some(ParseError::new(1, "three numbers").on_line(3)));
+        let error = Almanach::try_from("seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n").err();
+        assert_eq!(
+            error,
+            Some(ParseError::new(1, "`soil-to-fertilizer map:`").on_line(4))
+        );
+        let error = Almanach::try_from(
+            "seeds: 79 14 55
+",
+        )
+        .err()
+        .unwrap();
+        assert_eq!((error.line, error.column), (1, 16));
+        let error = Almanach::try_from(
+            "seeds: 79 14 55 0
+",
+        )
+        .err()
+        .unwrap();
+        assert_eq!((error.line, error.column), (1, 17));
+    }
+
+    #[test]
+    fn map_next_step() {
+        let maps = SeedMaps {
+            maps: vec![
+                SeedMap {
+                    destination: 50..52,
+                    source: 98..100,
+                },
+                SeedMap {
+                    destination: 52..100,
+                    source: 50..98,
+                },
+            ],
+        };
+
+        assert_eq!(maps.next_step(79), 81);
+        assert_eq!(maps.next_step(14), 14);
+        assert_eq!(maps.next_step(55), 57);
+        assert_eq!(maps.next_step(13), 13);
+    }
+
+    #[test]
+    fn location() {
+        let input = include_str!("../../fixtures/day-05-test");
+        let almanach = Almanach::try_from(input).unwrap();
+
+        assert_eq!(almanach.compute_seed_location(79), 82);
+        assert_eq!(almanach.compute_seed_location(14), 43);
+        assert_eq!(almanach.compute_seed_location(55), 86);
+        assert_eq!(almanach.compute_seed_location(13), 35);
+    }
+}
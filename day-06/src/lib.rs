@@ -0,0 +1,228 @@
+use common::{
+    generate::{Generate, Random},
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
+#[derive(Debug, PartialEq)]
+pub struct Races {
+    pub times: Vec<usize>,
+    pub distances: Vec<usize>,
+}
+
+impl Races {
+    pub fn count_record_breakers_for_one_race(time: usize, distance: usize) -> usize {
+        let (square, record) = ((time as u128).pow(2), 4 * distance as u128);
+        // a record reached by a single hold time at best cannot be beaten
+        if square <= record {
+            0
+        } else {
+            let delta = square - record;
+            let sqrt = (delta as f64).sqrt();
+            let float_solution1 = (time as f64 + sqrt) / 2.;
+            let float_solution2 = (time as f64 - sqrt) / 2.;
+
+            let solution1 = if float_solution1.fract() == 0.0 {
+                float_solution1 as usize - 1
+            } else {
+                float_solution1.floor() as usize
+            };
+            let solution2 = if float_solution2.fract() == 0.0 {
+                float_solution2 as usize + 1
+            } else {
+                float_solution2.ceil() as usize
+            };
+            (solution1 + 1).saturating_sub(solution2)
+        }
+    }
+
+    /// A slow reference for `count_record_breakers_for_one_race`, trying every hold time.
+    pub fn count_record_breakers_by_enumeration(time: usize, distance: usize) -> usize {
+        (0..=time)
+            .filter(|hold| hold * (time - hold) > distance)
+            .count()
+    }
+
+    pub fn count_record_breakers(&self) -> usize {
+        let record_breakers: Vec<usize> = self
+            .times
+            .iter()
+            .zip(self.distances.iter())
+            .map(|(time, distance)| Races::count_record_breakers_for_one_race(*time, *distance))
+            .collect();
+        let mut result = 1;
+        for rb in record_breakers {
+            result *= rb;
+        }
+        result
+    }
+
+    // the numbers of the sheet are actually a single number with bad kerning
+    pub fn with_fixed_kerning(&self) -> Self {
+        // checked when parsing, a sheet built by hand saturates instead
+        let merge = |numbers: &[usize]| merge(numbers).unwrap_or(usize::MAX);
+        Self {
+            times: vec![merge(&self.times)],
+            distances: vec![merge(&self.distances)],
+        }
+    }
+}
+
+/// The number written by the given ones without spaces between them, if it fits in a `usize`.
+fn merge(numbers: &[usize]) -> Option<usize> {
+    numbers.iter().try_fold(0usize, |merged, &number| {
+        let digits = number.checked_ilog10().unwrap_or_default() + 1;
+        merged
+            .checked_mul(10usize.checked_pow(digits)?)?
+            .checked_add(number)
+    })
+}
+
+impl TryFrom<&str> for Races {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut lines = input.lines();
+        let mut numbers = |index, header: &str| {
+            let line = lines.next().unwrap_or_default();
+            let numbers = line
+                .strip_prefix(header)
+                .ok_or_else(|| ParseError::new(1, format!("`{}`", header)).on_line(index))?;
+            let parsed: Vec<usize> =
+                parse::numbers(line, numbers).map_err(|error| error.on_line(index))?;
+            if parsed.is_empty() {
+                return Err(ParseError::after(line, "a race at least").on_line(index));
+            }
+            // the second part reads the numbers of a line as a single one
+            for (count, number) in numbers.split_whitespace().enumerate() {
+                if merge(&parsed[..=count]).is_none() {
+                    let expected = "a number fitting in a race with the ones before";
+                    return Err(ParseError::at(line, number, expected).on_line(index));
+                }
+            }
+            Ok(parsed)
+        };
+        let times: Vec<usize> = numbers(0, "Time:")?;
+        let distances: Vec<usize> = numbers(1, "Distance:")?;
+        if distances.len() != times.len() {
+            let expected = format!("{} distances, one per race", times.len());
+            return Err(ParseError::new(1, expected).on_line(1));
+        }
+        Ok(Self { times, distances })
+    }
+}
+
+impl Solution for Races {
+    const DAY: u8 = 6;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Races::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        self.count_record_breakers().into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.with_fixed_kerning().count_record_breakers().into()
+    }
+}
+
+/// Up to 4 races, each record beaten by some ways of holding the button. `size` is the number
+/// of races.
+impl Generate for Races {
+    fn generate(random: &mut Random, size: usize) -> String {
+        let races: Vec<(u64, u64)> = (0..size.clamp(1, 4))
+            .map(|_| {
+                let time = random.number(7..=99);
+                (time, random.number(0..=(time * time - 1) / 4))
+            })
+            .collect();
+        let line = |numbers: Vec<u64>| {
+            numbers
+                .iter()
+                .map(|number| format!("{:>5}", number))
+                .collect::<String>()
+        };
+        let times = line(races.iter().map(|&(time, _)| time).collect());
+        let distances = line(races.iter().map(|&(_, distance)| distance).collect());
+        format!("Time:    {}\nDistance:{}\n", times, distances)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::generate::Random;
+
+    use crate::Races;
+
+    #[test]
+    fn parse() {
+        let input = include_str!("../../fixtures/day-06-test");
+        let races = Races::try_from(input).unwrap();
+        assert_eq!(
+            races,
+            Races {
+                times: vec![7, 15, 30],
+                distances: vec![9, 40, 200]
+            }
+        );
+    }
+
+    #[test]
+    fn parse_invalid_races() {
+        let error = Races::try_from("Time: 7 15\nDistance: 9 4O").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 13));
+        let error = Races::try_from("Time: 7 15\nDistance: 9").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+        let error = Races::try_from("Time: 7 15").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+        let error = Races::try_from("Time:\nDistance:\n").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 6));
+        let error = Races::try_from("Time: 99999999999 99999999999\nDistance: 1 1")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (1, 19));
+    }
+
+    #[test]
+    fn record_breakers_for_one_race() {
+        assert_eq!(Races::count_record_breakers_for_one_race(7, 9), 4);
+        assert_eq!(Races::count_record_breakers_for_one_race(15, 40), 8);
+        assert_eq!(Races::count_record_breakers_for_one_race(30, 200), 9);
+    }
+
+    #[test]
+    fn record_breakers_against_enumeration() {
+        let mut random = Random::new(6);
+        for _ in 0..1000 {
+            let time = random.size(0..=60);
+            let distance = random.size(0..=time * time / 4 + 2);
+            assert_eq!(
+                Races::count_record_breakers_for_one_race(time, distance),
+                Races::count_record_breakers_by_enumeration(time, distance),
+                "time {} and distance {}",
+                time,
+                distance
+            );
+        }
+    }
+
+    #[test]
+    fn record_breakers_with_fixed_kerning() {
+        let input = include_str!("../../fixtures/day-06-test");
+        let races = Races::try_from(input).unwrap().with_fixed_kerning();
+        assert_eq!(
+            races,
+            Races {
+                times: vec![71530],
+                distances: vec![940200]
+            }
+        );
+        let races = Races::try_from("Time: 4294967296 0\nDistance: 1 0").unwrap();
+        assert_eq!(
+            races.with_fixed_kerning().count_record_breakers(),
+            42949672959
+        );
+    }
+}
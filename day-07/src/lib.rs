@@ -0,0 +1,388 @@
+use common::{
+    generate::{Generate, Random},
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+use std::ops::Deref;
+
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy, Eq, Ord)]
+pub enum Card {
+    A,
+    K,
+    Q,
+    Jack,
+    T,
+    N9,
+    N8,
+    N7,
+    N6,
+    N5,
+    N4,
+    N3,
+    N2,
+    Joker,
+}
+
+impl Card {
+    // with jokers, the 'J' cards are the weakest ones but can act as any other card
+    pub fn new(value: char, jokers: bool) -> Result<Self, ParseError> {
+        Ok(match value {
+            'A' => Card::A,
+            'K' => Card::K,
+            'Q' => Card::Q,
+            'J' if jokers => Card::Joker,
+            'J' => Card::Jack,
+            'T' => Card::T,
+            '9' => Card::N9,
+            '8' => Card::N8,
+            '7' => Card::N7,
+            '6' => Card::N6,
+            '5' => Card::N5,
+            '4' => Card::N4,
+            '3' => Card::N3,
+            '2' => Card::N2,
+            _ => return Err(ParseError::new(1, "a card among `AKQJT98765432`")),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Eq, Ord)]
+pub enum Hand {
+    FiveOfAKind(Card, Card, Card, Card, Card),
+    FourOfAKind(Card, Card, Card, Card, Card),
+    FullHouse(Card, Card, Card, Card, Card),
+    ThreeOfAKind(Card, Card, Card, Card, Card),
+    TwoPair(Card, Card, Card, Card, Card),
+    OnePair(Card, Card, Card, Card, Card),
+    HighCard(Card, Card, Card, Card, Card),
+}
+
+impl Hand {
+    pub fn new(input: &str, jokers: bool) -> Result<Self, ParseError> {
+        let cards = input
+            .chars()
+            .enumerate()
+            .map(|(index, c)| Card::new(c, jokers).map_err(|error| error.offset(0, index)))
+            .collect::<Result<Vec<Card>, _>>()?;
+        if cards.len() != 5 {
+            return Err(ParseError::new(1, "a hand of five cards"));
+        }
+        let mut sorted_cards = cards.clone();
+        sorted_cards.sort();
+
+        Ok(match sorted_cards[..] {
+            // five of a kind
+            [c1, _, _, _, c5] if c1 == c5 => {
+                Hand::FiveOfAKind(cards[0], cards[1], cards[2], cards[3], cards[4])
+            }
+            // four of a kind
+            [c1, _, _, c4, c5] if c1 == c4 => {
+                if c5 == Card::Joker {
+                    Hand::FiveOfAKind(cards[0], cards[1], cards[2], cards[3], cards[4])
+                } else {
+                    Hand::FourOfAKind(cards[0], cards[1], cards[2], cards[3], cards[4])
+                }
+            }
+            [_, c2, _, _, c5] if c2 == c5 => {
+                if c5 == Card::Joker {
+                    Hand::FiveOfAKind(cards[0], cards[1], cards[2], cards[3], cards[4])
+                } else {
+                    Hand::FourOfAKind(cards[0], cards[1], cards[2], cards[3], cards[4])
+                }
+            }
+            // full house
+            [c1, _, c3, c4, c5] if c1 == c3 && c4 == c5 => {
+                if c5 == Card::Joker {
+                    Hand::FiveOfAKind(cards[0], cards[1], cards[2], cards[3], cards[4])
+                } else {
+                    Hand::FullHouse(cards[0], cards[1], cards[2], cards[3], cards[4])
+                }
+            }
+            [c1, c2, c3, _, c5] if c1 == c2 && c3 == c5 => {
+                if c5 == Card::Joker {
+                    Hand::FiveOfAKind(cards[0], cards[1], cards[2], cards[3], cards[4])
+                } else {
+                    Hand::FullHouse(cards[0], cards[1], cards[2], cards[3], cards[4])
+                }
+            }
+            // three of a kind
+            [c1, _, c3, _, c5] if c1 == c3 => {
+                if c5 == Card::Joker {
+                    Hand::FourOfAKind(cards[0], cards[1], cards[2], cards[3], cards[4])
+                } else {
+                    Hand::ThreeOfAKind(cards[0], cards[1], cards[2], cards[3], cards[4])
+                }
+            }
+            [_, c2, _, c4, c5] if c2 == c4 => {
+                if c5 == Card::Joker {
+                    Hand::FourOfAKind(cards[0], cards[1], cards[2], cards[3], cards[4])
+                } else {
+                    Hand::ThreeOfAKind(cards[0], cards[1], cards[2], cards[3], cards[4])
+                }
+            }
+            [_, _, c3, _, c5] if c3 == c5 => {
+                if c5 == Card::Joker {
+                    Hand::FourOfAKind(cards[0], cards[1], cards[2], cards[3], cards[4])
+                } else {
+                    Hand::ThreeOfAKind(cards[0], cards[1], cards[2], cards[3], cards[4])
+                }
+            }
+            // two pair
+            [c1, c2, c3, c4, c5] if c1 == c2 && c3 == c4 => {
+                if c5 == Card::Joker {
+                    Hand::FullHouse(cards[0], cards[1], cards[2], cards[3], cards[4])
+                } else {
+                    Hand::TwoPair(cards[0], cards[1], cards[2], cards[3], cards[4])
+                }
+            }
+            [c1, c2, _, c4, c5] if c1 == c2 && c4 == c5 => {
+                if c5 == Card::Joker {
+                    Hand::FourOfAKind(cards[0], cards[1], cards[2], cards[3], cards[4])
+                } else {
+                    Hand::TwoPair(cards[0], cards[1], cards[2], cards[3], cards[4])
+                }
+            }
+            [_, c2, c3, c4, c5] if c2 == c3 && c4 == c5 => {
+                if c5 == Card::Joker {
+                    Hand::FourOfAKind(cards[0], cards[1], cards[2], cards[3], cards[4])
+                } else {
+                    Hand::TwoPair(cards[0], cards[1], cards[2], cards[3], cards[4])
+                }
+            }
+            // one pair
+            [c1, c2, _, _, c5] if c1 == c2 => {
+                if c5 == Card::Joker {
+                    Hand::ThreeOfAKind(cards[0], cards[1], cards[2], cards[3], cards[4])
+                } else {
+                    Hand::OnePair(cards[0], cards[1], cards[2], cards[3], cards[4])
+                }
+            }
+            [_, c2, c3, _, c5] if c2 == c3 => {
+                if c5 == Card::Joker {
+                    Hand::ThreeOfAKind(cards[0], cards[1], cards[2], cards[3], cards[4])
+                } else {
+                    Hand::OnePair(cards[0], cards[1], cards[2], cards[3], cards[4])
+                }
+            }
+            [_, _, c3, c4, c5] if c3 == c4 => {
+                if c5 == Card::Joker {
+                    Hand::ThreeOfAKind(cards[0], cards[1], cards[2], cards[3], cards[4])
+                } else {
+                    Hand::OnePair(cards[0], cards[1], cards[2], cards[3], cards[4])
+                }
+            }
+            [_, _, _, c4, c5] if c4 == c5 => {
+                if c5 == Card::Joker {
+                    Hand::ThreeOfAKind(cards[0], cards[1], cards[2], cards[3], cards[4])
+                } else {
+                    Hand::OnePair(cards[0], cards[1], cards[2], cards[3], cards[4])
+                }
+            }
+            // high card
+            [_, _, _, _, c5] => {
+                if c5 == Card::Joker {
+                    Hand::OnePair(cards[0], cards[1], cards[2], cards[3], cards[4])
+                } else {
+                    Hand::HighCard(cards[0], cards[1], cards[2], cards[3], cards[4])
+                }
+            }
+            _ => unreachable!("a hand has five cards"),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, PartialOrd)]
+pub struct Bid {
+    pub bid: usize,
+    pub hand: Hand,
+}
+
+impl Bid {
+    pub fn new(value: &str, jokers: bool) -> Result<Self, ParseError> {
+        let (hand, bid) = value
+            .split_once(" ")
+            .ok_or_else(|| ParseError::after(value, "a bid after the hand"))?;
+        let hand = Hand::new(hand, jokers)?;
+        let bid = parse::number(value, bid)?;
+        Ok(Bid { bid, hand })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Bids {
+    pub bids: Vec<Bid>,
+}
+
+impl Bids {
+    pub fn total_winnings(&self) -> usize {
+        self.bids
+            .iter()
+            .enumerate()
+            .map(|(index, bid)| (index + 1) * bid.bid)
+            .sum()
+    }
+}
+
+impl Deref for Bids {
+    type Target = Vec<Bid>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.bids
+    }
+}
+
+impl Bids {
+    pub fn new(value: &str, jokers: bool) -> Result<Self, ParseError> {
+        let mut bids = parse::lines(value, |line| Bid::new(line, jokers))?;
+        bids.sort_by(|a, b| b.hand.cmp(&a.hand));
+        Ok(Bids { bids })
+    }
+}
+
+pub struct CamelCards {
+    pub bids: Bids,
+    pub bids_with_jokers: Bids,
+}
+
+impl Solution for CamelCards {
+    const DAY: u8 = 7;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            bids: Bids::new(input, false)?,
+            bids_with_jokers: Bids::new(input, true)?,
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        self.bids.total_winnings().into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.bids_with_jokers.total_winnings().into()
+    }
+}
+
+/// Hands of cards, often with pairs or more, and their bids. `size` is the number of hands.
+impl Generate for CamelCards {
+    fn generate(random: &mut Random, size: usize) -> String {
+        const CARDS: [char; 13] = [
+            'A', 'K', 'Q', 'J', 'T', '9', '8', '7', '6', '5', '4', '3', '2',
+        ];
+        let mut input = String::new();
+        for _ in 0..size.max(1) {
+            let mut hand: Vec<char> = vec![];
+            while hand.len() < 5 {
+                let card = match hand.is_empty() || random.chance(50) {
+                    true => *random.choose(&CARDS),
+                    false => *random.choose(&hand),
+                };
+                hand.push(card);
+            }
+            let hand: String = hand.into_iter().collect();
+            input.push_str(&format!("{} {}\n", hand, random.number(1..=1000)));
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Bids, Card, Hand};
+
+    #[test]
+    fn card_parsing() {
+        assert_eq!(
+            Hand::new("32T3K", false).unwrap(),
+            Hand::OnePair(Card::N3, Card::N2, Card::T, Card::N3, Card::K)
+        );
+        assert_eq!(
+            Hand::new("T55J5", false).unwrap(),
+            Hand::ThreeOfAKind(Card::T, Card::N5, Card::N5, Card::Jack, Card::N5)
+        );
+        assert_eq!(
+            Hand::new("KK677", false).unwrap(),
+            Hand::TwoPair(Card::K, Card::K, Card::N6, Card::N7, Card::N7)
+        );
+        assert_eq!(
+            Hand::new("KTJJT", false).unwrap(),
+            Hand::TwoPair(Card::K, Card::T, Card::Jack, Card::Jack, Card::T)
+        );
+        assert_eq!(
+            Hand::new("QQQJA", false).unwrap(),
+            Hand::ThreeOfAKind(Card::Q, Card::Q, Card::Q, Card::Jack, Card::A)
+        );
+        assert_eq!(
+            Hand::new("86452", false).unwrap(),
+            Hand::HighCard(Card::N8, Card::N6, Card::N4, Card::N5, Card::N2)
+        );
+    }
+
+    #[test]
+    fn card_parsing_step2() {
+        assert_eq!(
+            Hand::new("32T3K", true).unwrap(),
+            Hand::OnePair(Card::N3, Card::N2, Card::T, Card::N3, Card::K)
+        );
+        assert_eq!(
+            Hand::new("T55J5", true).unwrap(),
+            Hand::FourOfAKind(Card::T, Card::N5, Card::N5, Card::Joker, Card::N5)
+        );
+        assert_eq!(
+            Hand::new("KK677", true).unwrap(),
+            Hand::TwoPair(Card::K, Card::K, Card::N6, Card::N7, Card::N7)
+        );
+        assert_eq!(
+            Hand::new("KTJJT", true).unwrap(),
+            Hand::FourOfAKind(Card::K, Card::T, Card::Joker, Card::Joker, Card::T)
+        );
+        assert_eq!(
+            Hand::new("QQQJA", true).unwrap(),
+            Hand::FourOfAKind(Card::Q, Card::Q, Card::Q, Card::Joker, Card::A)
+        );
+        assert_eq!(
+            Hand::new("86452", true).unwrap(),
+            Hand::HighCard(Card::N8, Card::N6, Card::N4, Card::N5, Card::N2)
+        );
+    }
+
+    #[test]
+    fn invalid_bids() {
+        let error = Bids::new("32T3K 765\nT55X5 684", false).err().unwrap();
+        assert_eq!((error.line, error.column), (2, 4));
+        let error = Bids::new("32T3 765", false).err().unwrap();
+        assert_eq!(error.expected, "a hand of five cards");
+        let error = Bids::new("32T3K", false).err().unwrap();
+        assert_eq!((error.line, error.column), (1, 6));
+    }
+
+    #[test]
+    fn card_order() {
+        // the strongest cards come first
+        assert!(Card::A < Card::Jack);
+        assert!(Card::A < Card::Joker);
+    }
+
+    #[test]
+    fn hand_order() {
+        assert!(
+            Hand::HighCard(Card::A, Card::Joker, Card::N5, Card::N4, Card::N3)
+                > Hand::HighCard(Card::A, Card::N5, Card::N4, Card::N3, Card::N2)
+        );
+    }
+
+    #[test]
+    fn hand_order2() {
+        assert!(Hand::new("77888", false).unwrap() < Hand::new("77788", false).unwrap());
+    }
+
+    #[test]
+    fn hand_order_with_joker() {
+        assert!(Hand::new("J367J", true).unwrap() < Hand::new("J3749", true).unwrap());
+    }
+
+    #[test]
+    fn hand_order_with_joker2() {
+        assert!(Hand::new("JK6AA", true).unwrap() > Hand::new("JKJ5J", true).unwrap());
+    }
+}
@@ -0,0 +1,373 @@
+use common::{
+    generate::{Generate, Random},
+    parse::ParseError,
+    solution::{Answer, Solution},
+};
+use std::{
+    collections::{BTreeMap, HashSet},
+    ops::Deref,
+};
+
+pub enum Instruction {
+    Right,
+    Left,
+}
+
+impl TryFrom<char> for Instruction {
+    type Error = ParseError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'R' => Ok(Self::Right),
+            'L' => Ok(Self::Left),
+            _ => Err(ParseError::new(1, "`L` or `R`")),
+        }
+    }
+}
+
+pub struct Instructions {
+    pub instructions: Vec<Instruction>,
+}
+
+impl TryFrom<&str> for Instructions {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let instructions = value
+            .chars()
+            .enumerate()
+            .map(|(index, c)| Instruction::try_from(c).map_err(|error| error.offset(0, index)))
+            .collect::<Result<Vec<_>, _>>()?;
+        if instructions.is_empty() {
+            return Err(ParseError::new(1, "a list of instructions"));
+        }
+        Ok(Instructions { instructions })
+    }
+}
+
+impl Deref for Instructions {
+    type Target = Vec<Instruction>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.instructions
+    }
+}
+
+pub struct Node {
+    pub name: String,
+    pub left: String,
+    pub right: String,
+}
+
+impl TryFrom<&str> for Node {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (name, next) = value
+            .split_once(" = ")
+            .ok_or_else(|| ParseError::after(value, "` = `"))?;
+        let (left, right) = next
+            .strip_prefix("(")
+            .and_then(|next| next.strip_suffix(")"))
+            .and_then(|next| next.split_once(", "))
+            .ok_or_else(|| ParseError::at(value, next, "`(<left>, <right>)`"))?;
+        Ok(Node {
+            name: name.to_string(),
+            left: left.to_string(),
+            right: right.to_string(),
+        })
+    }
+}
+
+pub struct Nodes {
+    pub nodes: BTreeMap<String, Node>,
+}
+
+impl Deref for Nodes {
+    type Target = BTreeMap<String, Node>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.nodes
+    }
+}
+
+pub struct NavigationMap {
+    pub instructions: Instructions,
+    pub nodes: Nodes,
+}
+
+impl NavigationMap {
+    /// The steps from `start` to the first node `end` accepts, or `None` when the walk comes back
+    /// to a node at the same instruction before reaching one.
+    fn walk(&self, start: &str, end: impl Fn(&str) -> bool) -> Option<usize> {
+        let mut visited = HashSet::new();
+        let mut current_node_name = start;
+        let instructions = self.instructions.iter().enumerate().cycle();
+        for (steps, (index, instruction)) in instructions.enumerate() {
+            if end(current_node_name) {
+                return Some(steps);
+            }
+            if !visited.insert((current_node_name, index)) {
+                return None;
+            }
+            let current_node = self.nodes.get(current_node_name).expect("missing node");
+            current_node_name = match instruction {
+                Instruction::Left => &current_node.left,
+                Instruction::Right => &current_node.right,
+            };
+        }
+        None
+    }
+
+    /// The steps from `AAA` to `ZZZ`, when the map has both nodes and one leads to the other.
+    pub fn count_steps(&self) -> Option<usize> {
+        if !self.nodes.contains_key("AAA") {
+            return None;
+        }
+        self.walk("AAA", |name| name == "ZZZ")
+    }
+
+    pub fn count_steps2(&self, start: &str) -> Option<usize> {
+        self.walk(start, |name| name.ends_with("Z"))
+    }
+
+    /// The steps until every ghost stands on a node ending in `Z`, when each of them reaches one.
+    pub fn count_steps_for_ghosts(&self) -> Option<usize> {
+        let initial_node_names: Vec<&String> = self
+            .nodes
+            .keys()
+            .filter(|name| name.ends_with("A"))
+            .collect();
+        let steps: Vec<usize> = initial_node_names
+            .iter()
+            .map(|name| self.count_steps2(name))
+            .collect::<Option<_>>()?;
+        let mut lcm = 1;
+        for step in steps {
+            lcm = num::integer::lcm(lcm, step);
+        }
+        Some(lcm)
+    }
+
+    /// A slow reference for `count_steps_for_ghosts`, moving every ghost one step at a time until
+    /// they all stand on a node ending in `Z`, or until they all come back to where they already
+    /// were at the same instruction.
+    pub fn count_steps_for_ghosts_by_simulation(&self) -> Option<usize> {
+        let mut ghosts: Vec<&String> = self
+            .nodes
+            .keys()
+            .filter(|name| name.ends_with("A"))
+            .collect();
+        let mut visited = HashSet::new();
+        let mut steps = 0;
+        let mut instructions = self.instructions.iter().enumerate().cycle();
+        while !ghosts.iter().all(|name| name.ends_with("Z")) {
+            let (index, instruction) = instructions.next()?;
+            if !visited.insert((ghosts.clone(), index)) {
+                return None;
+            }
+            for ghost in ghosts.iter_mut() {
+                let node = self.nodes.get(*ghost).expect("missing node");
+                *ghost = match instruction {
+                    Instruction::Left => &node.left,
+                    Instruction::Right => &node.right,
+                };
+            }
+            steps += 1;
+        }
+        Some(steps)
+    }
+}
+
+impl TryFrom<&str> for NavigationMap {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut lines = value.lines().enumerate();
+        let instructions = Instructions::try_from(lines.next().unwrap_or_default().1)?;
+        lines.next();
+
+        let mut nodes = BTreeMap::new();
+        let mut references = vec![];
+        for (index, line) in lines {
+            let node = Node::try_from(line).map_err(|error| error.on_line(index))?;
+            references.push((index, line, node.left.clone()));
+            references.push((index, line, node.right.clone()));
+            nodes.insert(node.name.clone(), node);
+        }
+        // every node has to lead somewhere
+        if let Some((index, line, name)) = references
+            .iter()
+            .find(|(_, _, name)| !nodes.contains_key(name))
+        {
+            let column = line.rfind(name.as_str()).unwrap_or_default() + 1;
+            let expected = format!("a node named `{}` in the map", name);
+            return Err(ParseError::new(column, expected).on_line(*index));
+        }
+        let nodes = Nodes { nodes };
+        Ok(NavigationMap {
+            instructions,
+            nodes,
+        })
+    }
+}
+
+impl Solution for NavigationMap {
+    const DAY: u8 = 8;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        NavigationMap::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        match self.count_steps() {
+            Some(steps) => steps.into(),
+            None => "no way from `AAA` to `ZZZ`".into(),
+        }
+    }
+
+    fn part2(&self) -> Answer {
+        match self.count_steps_for_ghosts() {
+            Some(steps) => steps.into(),
+            None => "a ghost never reaches a node ending in `Z`".into(),
+        }
+    }
+}
+
+/// A map walked by up to 6 ghosts, starting from `AAA` and other nodes ending in `A`. Each ghost
+/// reaches its node ending in `Z` after a multiple of the number of instructions, then loops back
+/// to where it went from its start, as the puzzle promises. `size` bounds the number of
+/// instructions and of their repetitions, up to 40 each.
+impl Generate for NavigationMap {
+    fn generate(random: &mut Random, size: usize) -> String {
+        const LETTERS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+        let limit = size.clamp(1, 40);
+        let instructions: Vec<bool> = (0..random.size(1..=limit))
+            .map(|_| random.chance(50))
+            .collect();
+        let mut count = 0;
+        // only the names of the starts and ends have an `A` or a `Z` at the end
+        let mut name = || {
+            let bytes = [
+                LETTERS[count / 624 % 26],
+                LETTERS[count / 24 % 26],
+                LETTERS[1 + count % 24],
+            ];
+            count += 1;
+            String::from_utf8(bytes.to_vec()).expect("ASCII letters")
+        };
+        let mut nodes: Vec<(String, String, String)> = vec![];
+        let ghosts = random.size(1..=6);
+        for (ghost, &letter) in LETTERS[..ghosts].iter().enumerate() {
+            let (start, end) = match ghost {
+                0 => ("AAA".to_string(), "ZZZ".to_string()),
+                _ => {
+                    let letter = letter as char;
+                    (format!("{0}{0}A", letter), format!("{0}{0}Z", letter))
+                }
+            };
+            let steps = instructions.len() * random.size(1..=limit);
+            let mut path = vec![start];
+            path.extend((1..steps).map(|_| name()));
+            path.push(end);
+            // the side the ghost does not take leads anywhere on its path
+            let mut next: Vec<(String, String)> = (0..steps)
+                .map(|step| {
+                    let taken = path[step + 1].clone();
+                    let other = random.choose(&path).clone();
+                    match instructions[step % instructions.len()] {
+                        true => (other, taken),
+                        false => (taken, other),
+                    }
+                })
+                .collect();
+            next.push(next[0].clone());
+            nodes.extend(
+                path.into_iter()
+                    .zip(next)
+                    .map(|(node, (left, right))| (node, left, right)),
+            );
+        }
+        random.shuffle(&mut nodes);
+        let instructions: String = instructions
+            .iter()
+            .map(|&right| if right { 'R' } else { 'L' })
+            .collect();
+        let mut input = format!("{}\n\n", instructions);
+        for (node, left, right) in nodes {
+            input.push_str(&format!("{} = ({}, {})\n", node, left, right));
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{
+        generate::{Generate, Random},
+        solution::{Answer, Solution},
+    };
+
+    use crate::NavigationMap;
+
+    #[test]
+    fn steps_without_start() {
+        let input = include_str!("../../fixtures/day-08-test3");
+        let navigation_map = NavigationMap::parse(input).unwrap();
+        assert_eq!(navigation_map.count_steps(), None);
+        assert_eq!(
+            navigation_map.part1(),
+            Answer::Text("no way from `AAA` to `ZZZ`".to_string())
+        );
+    }
+
+    #[test]
+    fn steps_without_end() {
+        let input = "L\n\nAAA = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)\n";
+        let navigation_map = NavigationMap::parse(input).unwrap();
+        assert_eq!(navigation_map.count_steps(), None);
+        assert_eq!(navigation_map.count_steps_for_ghosts(), None);
+        assert_eq!(navigation_map.count_steps_for_ghosts_by_simulation(), None);
+        assert_eq!(
+            navigation_map.part2(),
+            Answer::Text("a ghost never reaches a node ending in `Z`".to_string())
+        );
+        let input = "LR\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)\n";
+        let navigation_map = NavigationMap::parse(input).unwrap();
+        assert_eq!(navigation_map.count_steps(), None);
+        let input = "LR\n\nAAA = (BBB, AAA)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)\n";
+        let navigation_map = NavigationMap::parse(input).unwrap();
+        assert_eq!(navigation_map.count_steps(), Some(2));
+    }
+
+    #[test]
+    fn invalid_map() {
+        let error = NavigationMap::try_from("LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (4, 13));
+        let error = NavigationMap::try_from("LRX\n\nAAA = (AAA, AAA)")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (1, 3));
+        let error = NavigationMap::try_from("LR\n\nAAA = AAA, AAA")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (3, 7));
+    }
+
+    #[test]
+    fn steps_for_ghosts_against_simulation() {
+        let mut inputs = vec![include_str!("../../fixtures/day-08-test3").to_string()];
+        inputs.extend((0..50).map(|seed| NavigationMap::generate(&mut Random::new(seed), 3)));
+        for input in inputs {
+            let navigation_map = NavigationMap::try_from(input.as_str()).unwrap();
+            assert_eq!(
+                navigation_map.count_steps_for_ghosts(),
+                navigation_map.count_steps_for_ghosts_by_simulation(),
+                "\n{}",
+                input
+            );
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use common::{
+    generate::{Generate, Random},
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+use std::ops::Deref;
+
+pub struct History {
+    pub values: Vec<isize>,
+}
+
+impl History {
+    pub fn compute_next_value(&self) -> isize {
+        let mut differences: Vec<Vec<isize>> = vec![self.values.clone()];
+        loop {
+            let last_diff = differences.last().expect("missing diff vec");
+            if last_diff.iter().all(|&value| value == 0) {
+                break;
+            }
+            let new_diff = last_diff
+                .windows(2)
+                .map(|window| window[1] - window[0])
+                .collect();
+            differences.push(new_diff);
+        }
+        let mut projections = vec![0; differences.len()];
+        for (index, diff) in differences.iter().enumerate().rev().skip(1) {
+            projections[index] = diff[diff.len() - 1] + projections[index + 1];
+        }
+        projections[0]
+    }
+
+    pub fn compute_previous_value(&self) -> isize {
+        let mut differences: Vec<Vec<isize>> = vec![self.values.clone()];
+        loop {
+            let last_diff = differences.last().expect("missing diff vec");
+            if last_diff.iter().all(|&value| value == 0) {
+                break;
+            }
+            let new_diff = last_diff
+                .windows(2)
+                .map(|window| window[1] - window[0])
+                .collect();
+            differences.push(new_diff);
+        }
+        let mut projections = vec![0; differences.len()];
+        for (index, diff) in differences.iter().enumerate().rev().skip(1) {
+            projections[index] = diff[0] - projections[index + 1];
+        }
+        projections[0]
+    }
+}
+
+impl TryFrom<&str> for History {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let values: Vec<isize> = parse::numbers(input, input)?;
+        if values.is_empty() {
+            return Err(ParseError::new(1, "a list of values"));
+        }
+        Ok(Self { values })
+    }
+}
+
+pub struct Histories {
+    pub histories: Vec<History>,
+}
+
+impl Histories {
+    pub fn add_projections(&self) -> isize {
+        self.iter()
+            .map(|history| history.compute_next_value())
+            .sum()
+    }
+
+    pub fn add_prev_projections(&self) -> isize {
+        self.iter()
+            .map(|history| history.compute_previous_value())
+            .sum()
+    }
+}
+
+impl Deref for Histories {
+    type Target = Vec<History>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.histories
+    }
+}
+
+impl TryFrom<&str> for Histories {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let histories = parse::lines(input, |line| History::try_from(line))?;
+        Ok(Self { histories })
+    }
+}
+
+impl Solution for Histories {
+    const DAY: u8 = 9;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Histories::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        self.add_projections().into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.add_prev_projections().into()
+    }
+}
+
+/// Histories of values following polynomials of degree 4 at most, so that extrapolating them
+/// is exact. `size` is the number of histories.
+impl Generate for Histories {
+    fn generate(random: &mut Random, size: usize) -> String {
+        let mut input = String::new();
+        for _ in 0..size.max(1) {
+            let coefficients: Vec<i64> = (0..=random.size(0..=4))
+                .map(|_| random.signed(-5..=5))
+                .collect();
+            let values: Vec<String> = (0..random.size(5..=21) as i64)
+                .map(|x| {
+                    coefficients
+                        .iter()
+                        .rev()
+                        .fold(0, |value, coefficient| value * x + coefficient)
+                })
+                .map(|value| value.to_string())
+                .collect();
+            input.push_str(&values.join(" "));
+            input.push('\n');
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Histories;
+
+    #[test]
+    fn projection() {
+        let input = include_str!("../../fixtures/day-09-test");
+        let histories = Histories::try_from(input).unwrap();
+        let projection = histories[0].compute_next_value();
+        assert_eq!(projection, 18);
+    }
+
+    #[test]
+    fn prev_projection() {
+        let input = include_str!("../../fixtures/day-09-test");
+        let histories = Histories::try_from(input).unwrap();
+        let projection = histories[2].compute_previous_value();
+        assert_eq!(projection, 5);
+    }
+
+    #[test]
+    fn invalid_histories() {
+        let error = Histories::try_from("0 3 6\n1 3 -\n").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 5));
+        let error = Histories::try_from("0 3 6\n\n1 3 6").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+}
@@ -0,0 +1,328 @@
+use common::{
+    generate::{Generate, Random},
+    parse::ParseError,
+    solution::{Answer, Solution},
+};
+use grid::{random_loop, Direction, Grid, Position};
+use std::{
+    collections::VecDeque,
+    ops::{Deref, DerefMut},
+};
+
+#[derive(Debug, Clone)]
+pub struct Cell {
+    pub c: char,
+    pub is_path: bool,
+}
+
+impl Cell {
+    fn from_pipe(c: char) -> Self {
+        Self { c, is_path: false }
+    }
+}
+
+impl TryFrom<char> for Cell {
+    type Error = ParseError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        if !"|-LJ7F.S".contains(value) {
+            return Err(ParseError::new(1, "a tile among `|-LJ7F.S`"));
+        }
+        Ok(Cell::from_pipe(value))
+    }
+}
+
+impl Cell {
+    // the directions a pipe leads to
+    fn connections(&self) -> &'static [Direction] {
+        match self.c {
+            '|' => &[Direction::Up, Direction::Down],
+            '-' => &[Direction::Left, Direction::Right],
+            'L' => &[Direction::Up, Direction::Right],
+            'J' => &[Direction::Up, Direction::Left],
+            '7' => &[Direction::Down, Direction::Left],
+            'F' => &[Direction::Down, Direction::Right],
+            _ => &[],
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PipeMap {
+    pub map: Grid<Cell>,
+}
+
+impl PipeMap {
+    pub fn starting_point(&self) -> Option<Position> {
+        self.find(|cell| cell.c == 'S')
+    }
+
+    fn next_after_starting_point(&mut self) -> (Position, Position) {
+        let start = self.starting_point().expect("missing starting point");
+        let directions: Vec<Direction> = Direction::ALL
+            .into_iter()
+            .filter(|&direction| {
+                self.step(start, direction)
+                    .is_some_and(|point| self[point].connections().contains(&direction.opposite()))
+            })
+            .collect();
+        assert_eq!(directions.len(), 2);
+        // the starting point is replaced by the pipe it hides
+        self[start].c = "|-LJ7F"
+            .chars()
+            .find(|&c| Cell::from_pipe(c).connections() == directions)
+            .expect("the starting point connects two directions");
+        let next = |direction| self.step(start, direction).expect("checked above");
+        (next(directions[0]), next(directions[1]))
+    }
+
+    fn next_point(&self, origin: Position, current: Position) -> Position {
+        self[current]
+            .connections()
+            .iter()
+            .filter_map(|&direction| self.step(current, direction))
+            .find(|&point| point != origin)
+            .expect("the path is a loop")
+    }
+
+    fn mark_as_path(&mut self, point: Position) {
+        self[point].is_path = true;
+    }
+
+    pub fn furthest_distance(&mut self) -> usize {
+        let mut origin_a = self.starting_point().expect("missing starting point");
+        let mut origin_b = origin_a;
+        self.mark_as_path(origin_a);
+        let (mut current_a, mut current_b) = self.next_after_starting_point();
+        self.mark_as_path(current_a);
+        self.mark_as_path(current_b);
+        let mut steps = 1;
+
+        loop {
+            if current_a == current_b {
+                break;
+            }
+            let next_a = self.next_point(origin_a, current_a);
+            let next_b = self.next_point(origin_b, current_b);
+            origin_a = current_a;
+            origin_b = current_b;
+            current_a = next_a;
+            current_b = next_b;
+            self.mark_as_path(current_a);
+            self.mark_as_path(current_b);
+            steps += 1;
+        }
+        steps
+    }
+
+    pub fn count_inside_cells(&self) -> usize {
+        let mut result = 0;
+        self.rows().for_each(|line| {
+            let mut crossed = 0;
+            let mut previous_corner_was_up = false;
+            for cell in line.iter() {
+                if cell.is_path {
+                    match cell.c {
+                        '|' => crossed += 1,
+                        'F' => previous_corner_was_up = false,
+                        'L' => previous_corner_was_up = true,
+                        '7' if previous_corner_was_up => crossed += 1,
+                        'J' if !previous_corner_was_up => crossed += 1,
+                        _ => {
+                            // nothing
+                        }
+                    }
+                } else if crossed % 2 == 1 {
+                    result += 1;
+                }
+            }
+        });
+        result
+    }
+
+    /// A slow reference for `count_inside_cells`, once the path is marked. The map is drawn 3
+    /// times larger, each cell of the path as its pipe in the middle of a 3 by 3 square, so that
+    /// flooding from a corner squeezes between pipes and reaches every outside cell.
+    pub fn count_inside_cells_by_flood_fill(&self) -> usize {
+        let center = |(x, y): Position| (3 * x + 1, 3 * y + 1);
+        let mut walls = Grid::filled(3 * self.width(), 3 * self.height(), false);
+        for (position, cell) in self.iter().filter(|(_, cell)| cell.is_path) {
+            walls[center(position)] = true;
+            for &direction in cell.connections() {
+                let side = walls
+                    .step(center(position), direction)
+                    .expect("inside the square of the cell");
+                walls[side] = true;
+            }
+        }
+        let mut outside = Grid::filled(walls.width(), walls.height(), false);
+        outside[(0, 0)] = true;
+        let mut queue = VecDeque::from([(0, 0)]);
+        while let Some(position) = queue.pop_front() {
+            for next in walls.neighbours4(position) {
+                if !walls[next] && !outside[next] {
+                    outside[next] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+        self.iter()
+            .filter(|&(position, cell)| !cell.is_path && !outside[center(position)])
+            .count()
+    }
+}
+
+impl TryFrom<&str> for PipeMap {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let map = Grid::parse(input, Cell::try_from)?;
+        let starting_points: Vec<Position> = map
+            .iter()
+            .filter(|(_, cell)| cell.c == 'S')
+            .map(|(point, _)| point)
+            .collect();
+        match starting_points[..] {
+            [] => Err(ParseError::new(1, "a starting point `S`")),
+            [_] => Ok(Self { map }),
+            [_, (x, y), ..] => {
+                let error = ParseError::new(x + 1, "a single starting point `S`");
+                Err(error.on_line(y))
+            }
+        }
+    }
+}
+
+impl Deref for PipeMap {
+    type Target = Grid<Cell>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.map
+    }
+}
+
+impl DerefMut for PipeMap {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.map
+    }
+}
+
+impl Solution for PipeMap {
+    const DAY: u8 = 10;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        PipeMap::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        self.clone().furthest_distance().into()
+    }
+
+    fn part2(&self) -> Answer {
+        let mut pipe_map = self.clone();
+        // walking along the loop marks the cells of the path
+        pipe_map.furthest_distance();
+        pipe_map.count_inside_cells().into()
+    }
+}
+
+/// A map of `size` by `size` tiles, 4 at least, with a loop around a random shape starting at
+/// `S`. The other tiles hold random pipes, none of them connecting to `S`.
+impl Generate for PipeMap {
+    fn generate(random: &mut Random, size: usize) -> String {
+        const PIPES: [char; 6] = ['|', '-', 'L', 'J', '7', 'F'];
+        let side = size.max(4);
+        let path: Vec<Position> = random_loop(random, side - 2, side - 2)
+            .into_iter()
+            .map(|(x, y)| (x + 1, y + 1))
+            .collect();
+        let mut tiles = Grid::filled(side, side, '.');
+        for tile in tiles.cells_mut() {
+            if random.chance(40) {
+                *tile = *random.choose(&PIPES);
+            }
+        }
+        for (index, &position) in path.iter().enumerate() {
+            let previous = path[(index + path.len() - 1) % path.len()];
+            let next = path[(index + 1) % path.len()];
+            let directions = [previous, next]
+                .map(|other| Direction::between(position, other).expect("a continuous loop"));
+            tiles[position] = PIPES
+                .into_iter()
+                .find(|&pipe| {
+                    let connections = Cell::from_pipe(pipe).connections();
+                    directions
+                        .iter()
+                        .all(|direction| connections.contains(direction))
+                })
+                .expect("a pipe for every turn");
+        }
+        let start = *random.choose(&path);
+        tiles[start] = 'S';
+        let neighbours: Vec<Position> = tiles.neighbours4(start).collect();
+        for neighbour in neighbours {
+            if !path.contains(&neighbour) {
+                tiles[neighbour] = '.';
+            }
+        }
+        tiles.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::generate::{Generate, Random};
+
+    use crate::PipeMap;
+
+    #[test]
+    fn starting_point() {
+        let input = include_str!("../../fixtures/day-10-test");
+        let pipe_map = PipeMap::try_from(input).unwrap();
+        assert_eq!(pipe_map.starting_point(), Some((0, 2)));
+    }
+    #[test]
+    fn next_after_starting_point() {
+        let input = include_str!("../../fixtures/day-10-test");
+        let mut pipe_map = PipeMap::try_from(input).unwrap();
+        assert_eq!(pipe_map.next_after_starting_point(), ((0, 3), (1, 2)));
+    }
+
+    #[test]
+    fn next_point() {
+        let input = include_str!("../../fixtures/day-10-test");
+        let pipe_map = PipeMap::try_from(input).unwrap();
+        assert_eq!(pipe_map.next_point((0, 2), (0, 3)), (0, 4));
+        assert_eq!(pipe_map.next_point((0, 2), (1, 2)), (1, 1));
+    }
+
+    #[test]
+    fn count_inside_cells_against_flood_fill() {
+        let mut fixtures = vec![
+            include_str!("../../fixtures/day-10-test2").to_string(),
+            include_str!("../../fixtures/day-10-test3").to_string(),
+            include_str!("../../fixtures/day-10-test4").to_string(),
+        ];
+        fixtures.extend((0..50).map(|seed| PipeMap::generate(&mut Random::new(seed), 12)));
+        for input in fixtures {
+            let mut pipe_map = PipeMap::try_from(input.as_str()).unwrap();
+            pipe_map.furthest_distance();
+            assert_eq!(
+                pipe_map.count_inside_cells(),
+                pipe_map.count_inside_cells_by_flood_fill(),
+                "\n{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_maps() {
+        let error = PipeMap::try_from("..F7.\n.FJ|.\nSJ.Lx").err().unwrap();
+        assert_eq!((error.line, error.column), (3, 5));
+        let error = PipeMap::try_from("..F7.\n.FJ|.\n.J.L7").err().unwrap();
+        assert_eq!(error.expected, "a starting point `S`");
+        let error = PipeMap::try_from("..F7.\n.FJ|S\nSJ.L7").err().unwrap();
+        assert_eq!((error.line, error.column), (3, 1));
+    }
+}
@@ -0,0 +1,179 @@
+use common::{
+    generate::{Generate, Random},
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
+#[derive(Debug, PartialEq)]
+pub struct Galaxy {
+    pub coords: (usize, usize),
+}
+
+impl Galaxy {
+    pub fn distance_to(&self, other: &Galaxy) -> usize {
+        self.coords.0.abs_diff(other.coords.0) + self.coords.1.abs_diff(other.coords.1)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Universe {
+    pub galaxies: Vec<Galaxy>,
+}
+
+impl Universe {
+    pub fn sum_of_distances(&self) -> usize {
+        let mut sum = 0;
+        for index in 0..self.galaxies.len() {
+            let current_galaxy = &self.galaxies[index];
+            for other_galaxy in &self.galaxies[index + 1..self.galaxies.len()] {
+                sum += current_galaxy.distance_to(other_galaxy);
+            }
+        }
+        sum
+    }
+}
+
+impl Universe {
+    // every empty line or column is replaced by `expansion_factor` empty ones
+    pub fn expanded(input: &str, expansion_factor: usize) -> Result<Self, ParseError> {
+        let width = input.lines().next().map(str::len).unwrap_or_default();
+        parse::grid(input, |c| match c {
+            '.' | '#' => Ok(()),
+            _ => Err(ParseError::new(1, "`.` or `#`")),
+        })?;
+        let lines_without_galaxies: Vec<bool> = input
+            .lines()
+            .map(|line| line.chars().all(|c| c == '.'))
+            .collect();
+        let mut cols_have_no_galaxies: Vec<bool> = vec![true; width];
+        input.lines().for_each(|line| {
+            line.char_indices().for_each(|(index, c)| {
+                if c == '#' {
+                    cols_have_no_galaxies[index] = false
+                }
+            })
+        });
+
+        let mut galaxies = vec![];
+        let mut expanded_line_index = 0;
+        for (line_index, line) in input.lines().enumerate() {
+            if lines_without_galaxies[line_index] {
+                expanded_line_index += expansion_factor - 1;
+            } else {
+                let mut expanded_col_index = 0;
+                for (col_index, col) in line.char_indices() {
+                    if cols_have_no_galaxies[col_index] {
+                        expanded_col_index += expansion_factor - 1;
+                    } else {
+                        if col == '#' {
+                            galaxies.push(Galaxy {
+                                coords: (expanded_line_index, expanded_col_index),
+                            });
+                        }
+                    }
+                    expanded_col_index += 1;
+                }
+            }
+            expanded_line_index += 1;
+        }
+        Ok(Universe { galaxies })
+    }
+}
+
+impl TryFrom<&str> for Universe {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        Universe::expanded(input, 2)
+    }
+}
+
+pub struct Observations {
+    pub universe: Universe,
+    pub older_universe: Universe,
+}
+
+impl Solution for Observations {
+    const DAY: u8 = 11;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            universe: Universe::try_from(input)?,
+            older_universe: Universe::expanded(input, 1_000_000)?,
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        self.universe.sum_of_distances().into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.older_universe.sum_of_distances().into()
+    }
+}
+
+/// A square image of `size` by `size`, some of its rows and columns without galaxies.
+impl Generate for Observations {
+    fn generate(random: &mut Random, size: usize) -> String {
+        let side = size.max(2);
+        let empty_rows: Vec<bool> = (0..side).map(|_| random.chance(20)).collect();
+        let empty_columns: Vec<bool> = (0..side).map(|_| random.chance(20)).collect();
+        let mut input = String::new();
+        for is_row_empty in empty_rows {
+            for &is_column_empty in empty_columns.iter() {
+                let galaxy = !is_row_empty && !is_column_empty && random.chance(15);
+                input.push(if galaxy { '#' } else { '.' });
+            }
+            input.push('\n');
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Galaxy, Universe};
+
+    #[test]
+    fn expanding_universe() {
+        let input = include_str!("../../fixtures/day-11-test");
+        let universe = Universe::try_from(input).unwrap();
+        assert_eq!(
+            universe,
+            Universe {
+                galaxies: vec![
+                    Galaxy { coords: (0, 4) },
+                    Galaxy { coords: (1, 9) },
+                    Galaxy { coords: (2, 0) },
+                    Galaxy { coords: (5, 8) },
+                    Galaxy { coords: (6, 1) },
+                    Galaxy { coords: (7, 12) },
+                    Galaxy { coords: (10, 9) },
+                    Galaxy { coords: (11, 0) },
+                    Galaxy { coords: (11, 5) }
+                ]
+            }
+        );
+    }
+
+    #[test]
+    fn sum_of_distances_in_older_universes() {
+        let input = include_str!("../../fixtures/day-11-test");
+        assert_eq!(
+            Universe::expanded(input, 10).unwrap().sum_of_distances(),
+            1030
+        );
+        assert_eq!(
+            Universe::expanded(input, 100).unwrap().sum_of_distances(),
+            8410
+        );
+    }
+
+    #[test]
+    fn invalid_universe() {
+        let error = Universe::try_from("...#\n.#.\n").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 4));
+        let error = Universe::try_from("...#\n.#.o\n").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 4));
+    }
+}
@@ -0,0 +1,175 @@
+use common::{
+    generate::{Generate, Random},
+    parse::ParseError,
+    solution::{Answer, Solution},
+};
+use grid::Grid;
+
+pub struct Pattern {
+    pub ground: Grid<char>,
+}
+
+impl Pattern {
+    pub fn add_reflections(&self) -> usize {
+        Pattern::get_reflection(&self.ground.transpose(), false).unwrap_or_default()
+            + 100 * Pattern::get_reflection(&self.ground, false).unwrap_or_default()
+    }
+
+    pub fn add_reflections_with_smudge(&self) -> usize {
+        Pattern::get_reflection(&self.ground.transpose(), true).unwrap_or_default()
+            + 100 * Pattern::get_reflection(&self.ground, true).unwrap_or_default()
+    }
+
+    // the number of rows above a horizontal line of reflection, columns are checked on the
+    // transposed grid
+    fn get_reflection(grid: &Grid<char>, smudge: bool) -> Option<usize> {
+        let threshold = if smudge { 1 } else { 0 };
+        let mut result = None;
+        let len = grid.height();
+        for index in 0..len - 1 {
+            if Pattern::number_of_different_chars(grid.row(index), grid.row(index + 1)) <= threshold
+            {
+                let number_of_differences: usize = (0..=index)
+                    .rev()
+                    .zip(index + 1..len)
+                    .map(|(a, b)| Pattern::number_of_different_chars(grid.row(a), grid.row(b)))
+                    .sum();
+                if number_of_differences == threshold {
+                    result = Some(index + 1);
+                    break;
+                }
+            }
+        }
+        result
+    }
+
+    fn number_of_different_chars(row1: &[char], row2: &[char]) -> usize {
+        row1.iter()
+            .zip(row2.iter())
+            .filter(|(c1, c2)| c1 != c2)
+            .count()
+    }
+}
+
+impl TryFrom<&str> for Pattern {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let ground = Grid::parse(input, |c| match c {
+            '.' | '#' => Ok(c),
+            _ => Err(ParseError::new(1, "`.` or `#`")),
+        })?;
+        if ground.width() == 0 {
+            return Err(ParseError::new(1, "a pattern"));
+        }
+        Ok(Self { ground })
+    }
+}
+
+pub struct Patterns {
+    pub patterns: Vec<Pattern>,
+}
+
+impl Patterns {
+    pub fn sum_of_reflections(&self) -> usize {
+        self.patterns
+            .iter()
+            .map(|pattern| pattern.add_reflections())
+            .sum()
+    }
+
+    pub fn sum_of_reflections_with_smudge(&self) -> usize {
+        self.patterns
+            .iter()
+            .map(|pattern| pattern.add_reflections_with_smudge())
+            .sum()
+    }
+}
+
+impl TryFrom<&str> for Patterns {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut patterns = vec![];
+        let mut first_line_index = 0;
+        let mut string = String::new();
+        // an extra empty line ends the last pattern
+        for (index, line) in input.lines().chain([""]).enumerate() {
+            if line.is_empty() {
+                if !string.is_empty() {
+                    let pattern = Pattern::try_from(string.as_str())
+                        .map_err(|error| error.on_line(first_line_index))?;
+                    patterns.push(pattern);
+                }
+                first_line_index = index + 1;
+                string = String::new()
+            } else {
+                string.push_str(line);
+                string.push('\n');
+            }
+        }
+        Ok(Patterns { patterns })
+    }
+}
+
+impl Solution for Patterns {
+    const DAY: u8 = 13;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Patterns::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        self.sum_of_reflections().into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.sum_of_reflections_with_smudge().into()
+    }
+}
+
+/// Patterns of 5 to 15 rows and columns, each with a line of reflection between its rows or its
+/// columns. `size` is the number of patterns.
+impl Generate for Patterns {
+    fn generate(random: &mut Random, size: usize) -> String {
+        let patterns: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                let (width, height) = (random.size(5..=15), random.size(5..=15));
+                let mut ground = Grid::filled(width, height, '.');
+                for y in 0..height {
+                    for (x, cell) in random.string(width, "#.").chars().enumerate() {
+                        ground[(x, y)] = cell;
+                    }
+                }
+                let line = random.size(1..=height - 1);
+                for offset in 0..line.min(height - line) {
+                    for x in 0..width {
+                        ground[(x, line + offset)] = ground[(x, line - 1 - offset)];
+                    }
+                }
+                match random.chance(50) {
+                    true => ground.transpose().to_string(),
+                    false => ground.to_string(),
+                }
+            })
+            .collect();
+        patterns.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Patterns;
+
+    #[test]
+    fn invalid_patterns() {
+        let error = Patterns::try_from("#.##\n..#.\n\n#..#\n#..#\n.#.\n")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (6, 4));
+        let error = Patterns::try_from("#.##\n..#.\n\n\n#..#\n#.o#\n")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (6, 3));
+    }
+}
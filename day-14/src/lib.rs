@@ -0,0 +1,221 @@
+use common::{
+    generate::{Generate, Random},
+    parse::ParseError,
+    solution::{Answer, Solution},
+};
+use grid::Grid;
+use std::{collections::HashMap, fmt::Display};
+
+#[derive(Debug, PartialEq, Clone, Eq, Hash)]
+pub struct Platform {
+    pub rocks: Grid<char>,
+}
+
+impl Platform {
+    pub fn tilt_north(&self) -> Self {
+        let mut rocks = self.rocks.clone();
+        for x in 0..rocks.width() {
+            let mut first_available_spot = 0;
+            for y in 0..rocks.height() {
+                match rocks[(x, y)] {
+                    'O' => {
+                        rocks[(x, y)] = '.';
+                        rocks[(x, first_available_spot)] = 'O';
+                        first_available_spot += 1;
+                    }
+                    '#' => first_available_spot = y + 1,
+                    _ => {
+                        // nothing to do
+                    }
+                }
+            }
+        }
+        Self { rocks }
+    }
+
+    // the other directions are rotated to the north, tilted and rotated back
+    fn rotate_clockwise(&self) -> Self {
+        Self {
+            rocks: self.rocks.rotate_clockwise(),
+        }
+    }
+
+    fn rotate_counterclockwise(&self) -> Self {
+        Self {
+            rocks: self.rocks.rotate_counterclockwise(),
+        }
+    }
+
+    pub fn tilt_west(&self) -> Self {
+        self.rotate_clockwise()
+            .tilt_north()
+            .rotate_counterclockwise()
+    }
+
+    pub fn tilt_east(&self) -> Self {
+        self.rotate_counterclockwise()
+            .tilt_north()
+            .rotate_clockwise()
+    }
+
+    pub fn tilt_south(&self) -> Self {
+        self.rotate_clockwise()
+            .rotate_clockwise()
+            .tilt_north()
+            .rotate_clockwise()
+            .rotate_clockwise()
+    }
+
+    pub fn cycle(&self) -> Self {
+        // after each quarter turn, the next direction of the cycle is at the north
+        (0..4).fold(self.clone(), |platform, _| {
+            platform.tilt_north().rotate_clockwise()
+        })
+    }
+
+    pub fn count_north_load(&self) -> usize {
+        let height = self.rocks.height();
+        self.rocks
+            .rows()
+            .enumerate()
+            .map(|(index, row)| row.iter().filter(|&&c| c == 'O').count() * (height - index))
+            .sum()
+    }
+
+    fn find_repetitions(&self) -> (HashMap<Platform, usize>, usize, usize) {
+        let mut iteration = 0;
+
+        let mut repetitions = HashMap::new();
+        repetitions.insert(self.clone(), iteration);
+        let mut current_platform = self.clone();
+        let id = loop {
+            current_platform = current_platform.cycle();
+            iteration += 1;
+            if let Some(&index) = repetitions.get(&current_platform) {
+                break index;
+            } else {
+                repetitions.insert(current_platform.clone(), iteration);
+            }
+        };
+        (repetitions, id, iteration)
+    }
+
+    pub fn load_after_one_billion_cycles(&self) -> usize {
+        let (repetition_hashmap, index_in_hashmap, iteration) = self.find_repetitions();
+        let index =
+            (1_000_000_000 - index_in_hashmap) % (iteration - index_in_hashmap) + index_in_hashmap;
+        repetition_hashmap
+            .iter()
+            .find(|(_, &i)| index == i)
+            .map(|(platform, _)| platform.count_north_load())
+            .unwrap()
+    }
+}
+
+impl Display for Platform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.rocks)
+    }
+}
+
+impl TryFrom<&str> for Platform {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let rocks = Grid::parse(input, |c| match c {
+            'O' | '#' | '.' => Ok(c),
+            _ => Err(ParseError::new(1, "a rock among `O#.`")),
+        })?;
+        Ok(Platform { rocks })
+    }
+}
+
+impl Solution for Platform {
+    const DAY: u8 = 14;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Platform::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        self.tilt_north().count_north_load().into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.load_after_one_billion_cycles().into()
+    }
+}
+
+/// A square platform of `size` by `size`, with round and cube-shaped rocks.
+impl Generate for Platform {
+    fn generate(random: &mut Random, size: usize) -> String {
+        let side = size.max(1);
+        (0..side)
+            .map(|_| {
+                let row: String = (0..side)
+                    .map(|_| match random.number(0..=99) {
+                        0..=19 => 'O',
+                        20..=34 => '#',
+                        _ => '.',
+                    })
+                    .collect();
+                row + "\n"
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Platform;
+
+    #[test]
+    fn three_cycles() {
+        let input = include_str!("../../fixtures/day-14-test");
+        let platform = Platform::try_from(input).unwrap();
+        let platform2 = platform.cycle();
+        assert_eq!(
+            platform2,
+            Platform::try_from(
+                ".....#....\n....#...O#\n...OO##...\n.OO#......\n.....OOO#.\n\
+                 .O#...O#.#\n....O#....\n......OOOO\n#...O###..\n#..OO#...."
+            )
+            .unwrap()
+        );
+        let platform3 = platform2.cycle();
+        assert_eq!(
+            platform3,
+            Platform::try_from(
+                ".....#....\n....#...O#\n.....##...\n..O#......\n.....OOO#.\n\
+                 .O#...O#.#\n....O#...O\n.......OOO\n#..OO###..\n#.OOO#...O"
+            )
+            .unwrap()
+        );
+        let platform4 = platform3.cycle();
+        assert_eq!(
+            platform4,
+            Platform::try_from(
+                ".....#....\n....#...O#\n.....##...\n..O#......\n.....OOO#.\n\
+                 .O#...O#.#\n....O#...O\n.......OOO\n#...O###.O\n#.OOO#...O"
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn tilts() {
+        let platform = Platform::try_from("O.#\n..O\nO..").unwrap();
+        assert_eq!(platform.tilt_north().to_string(), "O.#\nO.O\n...\n");
+        assert_eq!(platform.tilt_south().to_string(), "..#\nO..\nO.O\n");
+        assert_eq!(platform.tilt_west().to_string(), "O.#\nO..\nO..\n");
+        assert_eq!(platform.tilt_east().to_string(), ".O#\n..O\n..O\n");
+    }
+
+    #[test]
+    fn invalid_platform() {
+        let error = Platform::try_from("O....\nO.OO#\n..o..").err().unwrap();
+        assert_eq!((error.line, error.column), (3, 3));
+        let error = Platform::try_from("O....\nO.OO\n").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 5));
+    }
+}
@@ -0,0 +1,209 @@
+use common::{
+    generate::{Generate, Random},
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
+#[derive(Default)]
+pub struct Hasher {
+    pub digest: u32,
+}
+
+impl Hasher {
+    pub fn new() -> Self {
+        Self { digest: 0 }
+    }
+
+    pub fn reset(&mut self) {
+        self.digest = 0;
+    }
+
+    pub fn add_ascii_char(&mut self, c: char) {
+        let code = c as u32;
+        let new_digest = ((self.digest + code) * 17) % 256;
+        self.digest = new_digest;
+    }
+
+    pub fn hash_str(&mut self, str: &str) -> u32 {
+        str.split(",")
+            .map(|s| {
+                self.reset();
+                s.chars()
+                    .filter(|&c| c != '\n')
+                    .for_each(|c| self.add_ascii_char(c));
+                self.digest
+            })
+            .sum()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Lens {
+    pub label: String,
+    pub focal: usize,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Box {
+    pub slots: Vec<Lens>,
+}
+
+impl Box {
+    pub fn new() -> Self {
+        Box { slots: vec![] }
+    }
+
+    pub fn remove_lens(&mut self, lens_label: &str) {
+        if let Some((index, _)) = self
+            .slots
+            .iter()
+            .enumerate()
+            .find(|(_, lens)| lens.label == lens_label)
+        {
+            self.slots.remove(index);
+        }
+    }
+
+    pub fn add_or_replace_lens(&mut self, lens_to_add: Lens) {
+        if let Some((index, _)) = self
+            .slots
+            .iter()
+            .enumerate()
+            .find(|(_, lens)| lens.label == lens_to_add.label)
+        {
+            self.slots[index] = lens_to_add;
+        } else {
+            self.slots.push(lens_to_add)
+        }
+    }
+
+    pub fn count_focusing_power(&self) -> usize {
+        self.slots
+            .iter()
+            .enumerate()
+            .map(|(index, lens)| (index + 1) * lens.focal)
+            .sum()
+    }
+}
+
+#[derive(Debug)]
+pub struct Boxes {
+    pub boxes: Vec<Box>,
+}
+
+impl Default for Boxes {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Boxes {
+    pub fn new() -> Self {
+        Self {
+            boxes: vec![Box::new(); 256],
+        }
+    }
+
+    pub fn process_instruction(&mut self, instruction: &str) -> Result<(), ParseError> {
+        let mut hasher = Hasher::new();
+        if let Some(label) = instruction.strip_suffix("-") {
+            let box_index = hasher.hash_str(label) as usize;
+            self.boxes[box_index].remove_lens(label);
+        } else {
+            let (label, focal) = instruction
+                .split_once("=")
+                .ok_or_else(|| ParseError::after(instruction, "`-` or `=`"))?;
+            let focal = parse::number(instruction, focal)?;
+            let box_index = hasher.hash_str(label) as usize;
+            self.boxes[box_index].add_or_replace_lens(Lens {
+                label: label.to_string(),
+                focal,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn process_instructions(&mut self, input: &str) -> Result<(), ParseError> {
+        input.split(",").try_for_each(|instruction| {
+            self.process_instruction(instruction)
+                .map_err(|error| error.offset(0, parse::column(input, instruction) - 1))
+        })
+    }
+
+    pub fn count_focusing_power(&self) -> usize {
+        self.boxes
+            .iter()
+            .enumerate()
+            .map(|(index, b)| (index + 1) * b.count_focusing_power())
+            .sum()
+    }
+}
+
+pub struct InitializationSequence {
+    pub sequence: String,
+    pub boxes: Boxes,
+}
+
+impl Solution for InitializationSequence {
+    const DAY: u8 = 15;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let sequence = input.trim_end().to_string();
+        let mut boxes = Boxes::new();
+        boxes.process_instructions(&sequence)?;
+        Ok(Self { sequence, boxes })
+    }
+
+    fn part1(&self) -> Answer {
+        Hasher::new().hash_str(&self.sequence).into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.boxes.count_focusing_power().into()
+    }
+}
+
+/// Steps inserting and removing lenses, their labels taken among a few so that lenses are often
+/// replaced or removed. `size` is the number of steps.
+impl Generate for InitializationSequence {
+    fn generate(random: &mut Random, size: usize) -> String {
+        let labels: Vec<String> = (0..size / 3 + 1)
+            .map(|_| {
+                let length = random.size(2..=6);
+                random.string(length, "abcdefghijklmnopqrstuvwxyz")
+            })
+            .collect();
+        let steps: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                let label = random.choose(&labels);
+                match random.chance(30) {
+                    true => format!("{}-", label),
+                    false => format!("{}={}", label, random.number(1..=9)),
+                }
+            })
+            .collect();
+        steps.join(",") + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Boxes, Hasher};
+
+    #[test]
+    fn hash() {
+        let input = "HASH";
+        let mut hasher = Hasher::new();
+        input.chars().for_each(|c| hasher.add_ascii_char(c));
+        assert_eq!(hasher.digest, 52);
+    }
+
+    #[test]
+    fn invalid_instructions() {
+        let mut boxes = Boxes::new();
+        let error = boxes.process_instructions("rn=1,cm-,qp=x").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 13));
+        let error = boxes.process_instructions("rn=1,cm,qp=3").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 8));
+    }
+}
@@ -0,0 +1,201 @@
+use common::{
+    generate::{Generate, Random},
+    parse::ParseError,
+    solution::{Answer, Solution},
+};
+use grid::{Direction, Grid, Position};
+
+#[derive(Clone)]
+pub enum TileKind {
+    Empty,
+    VSplitter,
+    HSplitter,
+    LMirror,
+    RMirror,
+}
+
+#[derive(Clone)]
+pub struct Tile {
+    pub kind: TileKind,
+    energized: bool,
+}
+
+impl Tile {
+    pub fn is_energized(&self) -> bool {
+        self.energized
+    }
+
+    fn energize(&mut self) {
+        self.energized = true;
+    }
+
+    fn de_energize(&mut self) {
+        self.energized = false;
+    }
+}
+
+impl TryFrom<char> for Tile {
+    type Error = ParseError;
+
+    fn try_from(input: char) -> Result<Self, Self::Error> {
+        let kind = match input {
+            '.' => TileKind::Empty,
+            '-' => TileKind::HSplitter,
+            '|' => TileKind::VSplitter,
+            '/' => TileKind::RMirror,
+            '\\' => TileKind::LMirror,
+            _ => return Err(ParseError::new(1, "a tile among `.-|/\\`")),
+        };
+        Ok(Self {
+            kind,
+            energized: false,
+        })
+    }
+}
+
+pub struct LightBeam {
+    pub direction: Direction,
+    pub position: Position,
+}
+
+impl LightBeam {
+    fn next(&self, tile: &Tile, tiles: &Grid<Tile>) -> Vec<LightBeam> {
+        use Direction::{Down, Left, Right, Up};
+        let directions = match (&tile.kind, self.direction) {
+            // an energized splitter has already sent its beams
+            (TileKind::HSplitter, Up | Down) | (TileKind::VSplitter, Left | Right)
+                if tile.is_energized() =>
+            {
+                vec![]
+            }
+            (TileKind::HSplitter, Up | Down) => vec![Left, Right],
+            (TileKind::VSplitter, Left | Right) => vec![Up, Down],
+            (TileKind::RMirror, Up) | (TileKind::LMirror, Down) => vec![Right],
+            (TileKind::RMirror, Down) | (TileKind::LMirror, Up) => vec![Left],
+            (TileKind::RMirror, Right) | (TileKind::LMirror, Left) => vec![Up],
+            (TileKind::RMirror, Left) | (TileKind::LMirror, Right) => vec![Down],
+            (_, direction) => vec![direction],
+        };
+        directions
+            .into_iter()
+            .filter_map(|direction| {
+                tiles
+                    .step(self.position, direction)
+                    .map(|position| LightBeam {
+                        direction,
+                        position,
+                    })
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone)]
+pub struct Contraption {
+    pub tiles: Grid<Tile>,
+}
+
+impl Contraption {
+    pub fn send_light_beam(&mut self, first_light_beam: LightBeam) {
+        let mut light_beams = vec![first_light_beam];
+        while let Some(light_beam) = light_beams.pop() {
+            light_beams.append(&mut light_beam.next(&self.tiles[light_beam.position], &self.tiles));
+            self.tiles[light_beam.position].energize();
+        }
+    }
+
+    pub fn count_energized_tiles(&self) -> usize {
+        self.tiles
+            .cells()
+            .filter(|tile| tile.is_energized())
+            .count()
+    }
+
+    pub fn reset(&mut self) {
+        self.tiles.cells_mut().for_each(|tile| tile.de_energize());
+    }
+
+    pub fn find_max_energy(&mut self) -> usize {
+        let width = self.tiles.width();
+        let height = self.tiles.height();
+        // From the top, the bottom, the left and the right
+        let first_light_beams = (0..width)
+            .map(|x| (Direction::Down, (x, 0)))
+            .chain((0..width).map(|x| (Direction::Up, (x, height - 1))))
+            .chain((0..height).map(|y| (Direction::Right, (0, y))))
+            .chain((0..height).map(|y| (Direction::Left, (width - 1, y))));
+        let mut max = 0;
+        for (direction, position) in first_light_beams {
+            self.reset();
+            self.send_light_beam(LightBeam {
+                direction,
+                position,
+            });
+            max = max.max(self.count_energized_tiles());
+        }
+        max
+    }
+}
+
+impl TryFrom<&str> for Contraption {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let tiles = Grid::parse(input, Tile::try_from)?;
+        if tiles.width() == 0 {
+            return Err(ParseError::new(1, "a tile"));
+        }
+        Ok(Self { tiles })
+    }
+}
+
+impl Solution for Contraption {
+    const DAY: u8 = 16;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Contraption::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        let mut contraption = self.clone();
+        contraption.send_light_beam(LightBeam {
+            direction: Direction::Right,
+            position: (0, 0),
+        });
+        contraption.count_energized_tiles().into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.clone().find_max_energy().into()
+    }
+}
+
+/// A square contraption of `size` by `size`, a tile in five holding a mirror or a splitter.
+impl Generate for Contraption {
+    fn generate(random: &mut Random, size: usize) -> String {
+        const DEVICES: [char; 4] = ['|', '-', '/', '\\'];
+        let side = size.max(1);
+        (0..side)
+            .map(|_| {
+                let row: String = (0..side)
+                    .map(|_| match random.chance(20) {
+                        true => *random.choose(&DEVICES),
+                        false => '.',
+                    })
+                    .collect();
+                row + "\n"
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Contraption;
+
+    #[test]
+    fn invalid_contraption() {
+        let error = Contraption::try_from(".|...\\\n|.-.x.").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 5));
+    }
+}
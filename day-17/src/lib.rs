@@ -0,0 +1,338 @@
+use common::{
+    generate::{Generate, Random},
+    parse::ParseError,
+    solution::{Answer, Solution},
+};
+use grid::{Direction, Grid};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+};
+
+pub struct City {
+    pub blocks: Grid<usize>,
+}
+
+pub enum Part {
+    One,
+    Two,
+}
+
+#[derive(Hash, PartialEq, Eq, Clone)]
+struct Crucible {
+    position: (usize, usize),
+    direction: Direction,
+    line_len: usize,
+}
+
+impl Crucible {
+    fn can_go_further(&self, part: &Part) -> bool {
+        match part {
+            Part::One => self.line_len < 3,
+            Part::Two => self.line_len < 10,
+        }
+    }
+
+    fn min_steps(&self, part: &Part) -> usize {
+        match part {
+            Part::One => 1,
+            Part::Two => 4,
+        }
+    }
+
+    fn went_far_enough(&self, part: &Part) -> bool {
+        self.line_len >= self.min_steps(part)
+    }
+}
+
+impl City {
+    // the purpose of these functions is to provide a feasible solution that will act as an
+    // upper bound value during the search
+    pub fn diagonal_path(&self) -> usize {
+        self.staircase_path(1, 3)
+    }
+
+    // same as diagonal_path, but for part 2
+    pub fn diagonal_path_part_2(&self) -> usize {
+        self.staircase_path(4, 10)
+    }
+
+    // a staircase of lines of `min` to `max` blocks, as many to the right as down, the crucible
+    // only turning once it went far enough; usize::MAX when no staircase fits in the city
+    fn staircase_path(&self, min: usize, max: usize) -> usize {
+        let (width, height) = (self.blocks.width(), self.blocks.height());
+        let count = (width.max(height) - 1).div_ceil(max).max(1);
+        let lines = |length: usize| -> Vec<usize> {
+            (0..count)
+                .map(|index| length * (index + 1) / count - length * index / count)
+                .collect()
+        };
+        let mut position = (0, 0);
+        let mut sum = 0;
+        for (right, down) in lines(width - 1).into_iter().zip(lines(height - 1)) {
+            if !(min..=max).contains(&right) || !(min..=max).contains(&down) {
+                return usize::MAX;
+            }
+            for _ in 0..right {
+                position.0 += 1;
+                sum += self.blocks[position];
+            }
+            for _ in 0..down {
+                position.1 += 1;
+                sum += self.blocks[position];
+            }
+        }
+        sum
+    }
+
+    /// The least heat lost on the way to the factory, if the crucible can get there at all.
+    pub fn dfs(&self, part: Part) -> Option<usize> {
+        let mut cache = HashMap::new();
+        let mut best_known_heat = match part {
+            Part::One => self.diagonal_path(),
+            Part::Two => self.diagonal_path_part_2(),
+        };
+        for (position, direction) in [((1, 0), Direction::Right), ((0, 1), Direction::Down)] {
+            if !self.blocks.contains(position) {
+                continue;
+            }
+            self.dfs_rec(
+                Crucible {
+                    position,
+                    direction,
+                    line_len: 1,
+                },
+                self.blocks[position],
+                &mut best_known_heat,
+                &mut cache,
+                &part,
+            );
+        }
+        // without any staircase to start from, the bound is only reached by finding a path
+        (best_known_heat != usize::MAX).then_some(best_known_heat)
+    }
+
+    fn dfs_rec(
+        &self,
+        crucible: Crucible,
+        heat: usize,
+        best_known_heat: &mut usize,
+        cache: &mut HashMap<Crucible, usize>,
+        part: &Part,
+    ) {
+        if heat > *best_known_heat {
+            return;
+        }
+
+        if heat < *cache.get(&crucible).unwrap_or(&usize::MAX) {
+            cache.insert(crucible.clone(), heat);
+        } else {
+            return;
+        }
+
+        if crucible.position == (self.blocks.width() - 1, self.blocks.height() - 1) {
+            if crucible.went_far_enough(part) && heat < *best_known_heat {
+                *best_known_heat = heat;
+            }
+            return;
+        }
+
+        for direction in Direction::ALL {
+            if direction == crucible.direction.opposite() {
+                continue;
+            }
+            let Some(next_position) = self.blocks.step(crucible.position, direction) else {
+                continue;
+            };
+            let line_len = if direction == crucible.direction {
+                if !crucible.can_go_further(part) {
+                    continue;
+                }
+                crucible.line_len + 1
+            } else {
+                // after turning, there must be room left for the minimum number of steps
+                let has_room = (0..crucible.min_steps(part))
+                    .try_fold(crucible.position, |position, _| {
+                        self.blocks.step(position, direction)
+                    })
+                    .is_some();
+                if !crucible.went_far_enough(part) || !has_room {
+                    continue;
+                }
+                1
+            };
+            self.dfs_rec(
+                Crucible {
+                    position: next_position,
+                    direction,
+                    line_len,
+                },
+                heat + self.blocks[next_position],
+                best_known_heat,
+                cache,
+                part,
+            );
+        }
+    }
+
+    /// A slow reference for `dfs`: Dijkstra's algorithm over the crucibles, without any bound.
+    pub fn dijkstra(&self, part: Part) -> Option<usize> {
+        let target = (self.blocks.width() - 1, self.blocks.height() - 1);
+        let mut best: HashMap<Crucible, usize> = HashMap::new();
+        let mut queue = BinaryHeap::new();
+        for (position, direction) in [((1, 0), Direction::Right), ((0, 1), Direction::Down)] {
+            if self.blocks.contains(position) {
+                queue.push(Reverse((self.blocks[position], position, direction, 1)));
+            }
+        }
+        while let Some(Reverse((heat, position, direction, line_len))) = queue.pop() {
+            let crucible = Crucible {
+                position,
+                direction,
+                line_len,
+            };
+            if position == target && crucible.went_far_enough(&part) {
+                return Some(heat);
+            }
+            if best.get(&crucible).is_some_and(|&known| known <= heat) {
+                continue;
+            }
+            best.insert(crucible.clone(), heat);
+            for next_direction in Direction::ALL {
+                if next_direction == direction.opposite() {
+                    continue;
+                }
+                let straight = next_direction == direction;
+                if straight && !crucible.can_go_further(&part)
+                    || !straight && !crucible.went_far_enough(&part)
+                {
+                    continue;
+                }
+                let Some(next_position) = self.blocks.step(position, next_direction) else {
+                    continue;
+                };
+                let next_line_len = if straight { line_len + 1 } else { 1 };
+                queue.push(Reverse((
+                    heat + self.blocks[next_position],
+                    next_position,
+                    next_direction,
+                    next_line_len,
+                )));
+            }
+        }
+        None
+    }
+}
+
+impl TryFrom<&str> for City {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let blocks = Grid::parse(input, |c| {
+            c.to_digit(10)
+                .map(|digit| digit as usize)
+                .ok_or(ParseError::new(1, "a digit"))
+        })?;
+        if blocks.width() == 0 {
+            return Err(ParseError::new(1, "a block"));
+        }
+        Ok(Self { blocks })
+    }
+}
+
+impl Solution for City {
+    const DAY: u8 = 17;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        City::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        match self.dfs(Part::One) {
+            Some(heat) => heat.into(),
+            None => "no way to the factory".into(),
+        }
+    }
+
+    fn part2(&self) -> Answer {
+        match self.dfs(Part::Two) {
+            Some(heat) => heat.into(),
+            None => "no way to the factory".into(),
+        }
+    }
+}
+
+/// A square city of `size` by `size` blocks, each losing 1 to 9 heat. The side is 5 at least,
+/// the ultra crucibles needing 4 blocks in a line to reach the factory.
+impl Generate for City {
+    fn generate(random: &mut Random, size: usize) -> String {
+        let side = size.max(5);
+        (0..side)
+            .map(|_| random.string(side, "123456789") + "\n")
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{
+        generate::{Generate, Random},
+        solution::{Answer, Solution},
+    };
+
+    use crate::{City, Part};
+
+    #[test]
+    fn diag() {
+        let input = include_str!("../../fixtures/day-17-test");
+        let city = City::try_from(input).unwrap();
+        assert!(city.diagonal_path() >= 102);
+    }
+
+    #[test]
+    fn diag2() {
+        let input = include_str!("../../fixtures/day-17-test");
+        let city = City::try_from(input).unwrap();
+        assert!(city.diagonal_path_part_2() >= 94);
+    }
+
+    #[test]
+    fn dfs_against_dijkstra() {
+        for seed in 0..30 {
+            let input = City::generate(&mut Random::new(seed), 5 + seed as usize % 8);
+            let city = City::try_from(input.as_str()).unwrap();
+            assert_eq!(city.dfs(Part::One), city.dijkstra(Part::One), "\n{}", input);
+            assert_eq!(city.dfs(Part::Two), city.dijkstra(Part::Two), "\n{}", input);
+        }
+    }
+
+    #[test]
+    fn small_and_thin_cities() {
+        let inputs = ["1\n", "12345\n12345\n", "1\n2\n3\n", "19\n91\n", "12345\n"];
+        for input in inputs {
+            let city = City::try_from(input).unwrap();
+            assert_eq!(city.dfs(Part::One), city.dijkstra(Part::One), "\n{}", input);
+            assert_eq!(city.dfs(Part::Two), city.dijkstra(Part::Two), "\n{}", input);
+        }
+        let city = City::try_from("1\n").unwrap();
+        assert_eq!(city.dfs(Part::One), None);
+        let city = City::try_from("12345\n12345\n").unwrap();
+        assert_eq!(city.dfs(Part::One), Some(16));
+        // the ultra crucible cannot turn down after going 4 blocks
+        assert_eq!(city.dfs(Part::Two), None);
+        assert_eq!(
+            city.part2(),
+            Answer::Text("no way to the factory".to_string())
+        );
+        let city = City::try_from("12345\n").unwrap();
+        assert_eq!(city.dfs(Part::Two), Some(14));
+    }
+
+    #[test]
+    fn invalid_city() {
+        let error = City::try_from("2413\n32x5\n").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 3));
+        let error = City::try_from("2413\n325\n").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 4));
+    }
+}
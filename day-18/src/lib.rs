@@ -0,0 +1,290 @@
+use common::{
+    generate::{Generate, Random},
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+use grid::{random_loop, Direction};
+use std::ops::Deref;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Instruction {
+    pub direction: Direction,
+    pub length: u64,
+}
+
+impl Instruction {
+    // the instruction hidden in a colour like `#70c710`: the length in the first five hex
+    // digits, the direction in the last one
+    fn from_colour(colour: &str) -> Result<Self, ParseError> {
+        let digits = colour
+            .strip_prefix("(#")
+            .and_then(|colour| colour.strip_suffix(")"))
+            .filter(|digits| digits.len() == 6 && digits.chars().all(|c| c.is_ascii_hexdigit()))
+            .ok_or_else(|| ParseError::new(1, "a colour like `(#70c710)`"))?;
+        let length = u64::from_str_radix(&digits[..5], 16).expect("checked above");
+        let direction = match &digits[5..] {
+            "0" => Direction::Right,
+            "1" => Direction::Down,
+            "2" => Direction::Left,
+            "3" => Direction::Up,
+            _ => return Err(ParseError::new(8, "a direction digit from 0 to 3")),
+        };
+        Ok(Self { direction, length })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PlanEntry {
+    pub instruction: Instruction,
+    pub colour_instruction: Instruction,
+}
+
+impl TryFrom<&str> for PlanEntry {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let mut parts = line.split(' ');
+        let mut next = |expected| {
+            parts
+                .next()
+                .filter(|part| !part.is_empty())
+                .ok_or_else(|| ParseError::after(line, expected))
+        };
+        let direction = next("a direction")?;
+        let length = next("a length")?;
+        let colour = next("a colour")?;
+        let direction = match direction {
+            "R" => Direction::Right,
+            "D" => Direction::Down,
+            "L" => Direction::Left,
+            "U" => Direction::Up,
+            _ => return Err(ParseError::at(line, direction, "`R`, `D`, `L` or `U`")),
+        };
+        let length = parse::number(line, length)?;
+        let colour_instruction = Instruction::from_colour(colour)
+            .map_err(|error| error.offset(0, parse::column(line, colour) - 1))?;
+        Ok(Self {
+            instruction: Instruction { direction, length },
+            colour_instruction,
+        })
+    }
+}
+
+pub struct DigPlan {
+    pub entries: Vec<PlanEntry>,
+}
+
+impl Deref for DigPlan {
+    type Target = Vec<PlanEntry>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.entries
+    }
+}
+
+impl TryFrom<&str> for DigPlan {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let entries = parse::lines(input, |line| PlanEntry::try_from(line))?;
+        let Some((index, line)) = input.lines().enumerate().last() else {
+            return Err(ParseError::new(1, "a plan entry"));
+        };
+        // the trench has to be a closed loop for the lagoon to have a volume
+        if !is_closed(entries.iter().map(|entry| entry.instruction)) {
+            let expected = "instructions coming back to the start";
+            return Err(ParseError::new(1, expected).on_line(index));
+        }
+        if !is_closed(entries.iter().map(|entry| entry.colour_instruction)) {
+            let colour = line.rsplit(' ').next().unwrap_or_default();
+            let expected = "colours coming back to the start";
+            return Err(ParseError::at(line, colour, expected).on_line(index));
+        }
+        Ok(Self { entries })
+    }
+}
+
+// whether following the instructions leads back to where they start
+fn is_closed(instructions: impl Iterator<Item = Instruction>) -> bool {
+    let end = instructions.fold((0_i128, 0_i128), |(x, y), instruction| {
+        let length = instruction.length as i128;
+        match instruction.direction {
+            Direction::Up => (x, y - length),
+            Direction::Down => (x, y + length),
+            Direction::Left => (x - length, y),
+            Direction::Right => (x + length, y),
+        }
+    });
+    end == (0, 0)
+}
+
+impl DigPlan {
+    pub fn lagoon_volume(&self) -> u64 {
+        lagoon_volume(self.iter().map(|entry| entry.instruction))
+    }
+
+    pub fn lagoon_volume_from_colours(&self) -> u64 {
+        lagoon_volume(self.iter().map(|entry| entry.colour_instruction))
+    }
+}
+
+/// The number of cubic meters dug by following the instructions around a closed loop.
+///
+/// The shoelace formula gives the area of the polygon going through the centers of the trench
+/// cells. Pick's theorem then counts the cells inside it, `area - boundary / 2 + 1`, to which the
+/// trench itself is added.
+pub fn lagoon_volume(instructions: impl Iterator<Item = Instruction>) -> u64 {
+    let (mut x, mut y) = (0_i64, 0_i64);
+    let mut double_area = 0;
+    let mut boundary = 0;
+    for instruction in instructions {
+        let length = instruction.length as i64;
+        let (next_x, next_y) = match instruction.direction {
+            Direction::Up => (x, y - length),
+            Direction::Down => (x, y + length),
+            Direction::Left => (x - length, y),
+            Direction::Right => (x + length, y),
+        };
+        double_area += x * next_y - next_x * y;
+        boundary += instruction.length;
+        (x, y) = (next_x, next_y);
+    }
+    (double_area.unsigned_abs() + boundary) / 2 + 1
+}
+
+impl Solution for DigPlan {
+    const DAY: u8 = 18;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        DigPlan::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        self.lagoon_volume().into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.lagoon_volume_from_colours().into()
+    }
+}
+
+/// A plan digging around a random shape, and the same shape at a much larger scale in the
+/// colours, so that both parts dig closed, non-crossing loops. `size` is the number of columns
+/// and rows of the shape.
+impl Generate for DigPlan {
+    fn generate(random: &mut Random, size: usize) -> String {
+        let side = size.max(2);
+        let path = random_loop(random, side, side);
+        // stretching the columns and rows keeps the shape of the loop
+        let mut scale =
+            |limit: u64| -> Vec<u64> { (0..side).map(|_| random.number(1..=limit)).collect() };
+        let (widths, heights) = (scale(10), scale(10));
+        let (large_widths, large_heights) = (
+            scale(1_000_000 / side as u64),
+            scale(1_000_000 / side as u64),
+        );
+        let mut runs: Vec<(Direction, u64, u64)> = vec![];
+        for (index, &from) in path.iter().enumerate() {
+            let to = path[(index + 1) % path.len()];
+            let direction = Direction::between(from, to).expect("a continuous loop");
+            let (length, large_length) = match direction.is_horizontal() {
+                true => (widths[from.0.min(to.0)], large_widths[from.0.min(to.0)]),
+                false => (heights[from.1.min(to.1)], large_heights[from.1.min(to.1)]),
+            };
+            match runs.last_mut() {
+                Some(run) if run.0 == direction => {
+                    run.1 += length;
+                    run.2 += large_length;
+                }
+                _ => runs.push((direction, length, large_length)),
+            }
+        }
+        let mut input = String::new();
+        for (direction, length, large_length) in runs {
+            let (letter, digit) = match direction {
+                Direction::Right => ('R', 0),
+                Direction::Down => ('D', 1),
+                Direction::Left => ('L', 2),
+                Direction::Up => ('U', 3),
+            };
+            input.push_str(&format!(
+                "{} {} (#{:05x}{})\n",
+                letter, length, large_length, digit
+            ));
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use grid::Direction;
+
+    use crate::{lagoon_volume, DigPlan, Instruction, PlanEntry};
+
+    #[test]
+    fn parse_entry() {
+        let entry = PlanEntry::try_from("R 6 (#70c710)").unwrap();
+        assert_eq!(
+            entry,
+            PlanEntry {
+                instruction: Instruction {
+                    direction: Direction::Right,
+                    length: 6
+                },
+                colour_instruction: Instruction {
+                    direction: Direction::Right,
+                    length: 461937
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn square() {
+        let square = [
+            Direction::Right,
+            Direction::Down,
+            Direction::Left,
+            Direction::Up,
+        ]
+        .map(|direction| Instruction {
+            direction,
+            length: 2,
+        });
+        assert_eq!(lagoon_volume(square.into_iter()), 9);
+    }
+
+    #[test]
+    fn invalid_plan() {
+        let error = DigPlan::try_from("R 6 (#70c710)\nX 5 (#0dc571)")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+        let error = DigPlan::try_from("R 6 (#70c710)\nD x (#0dc571)")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (2, 3));
+        let error = DigPlan::try_from("R 6 (#70c710)\nD 5 (#0dc574)")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (2, 12));
+        let error = DigPlan::try_from("R 6 (#70c710)\nD 5").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 4));
+    }
+
+    #[test]
+    fn open_plan() {
+        let error = DigPlan::try_from("R 2 (#000020)\nD 2 (#000021)")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+        let error = DigPlan::try_from("R 2 (#000020)\nL 2 (#000012)")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (2, 5));
+        let error = DigPlan::try_from("").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 1));
+        assert!(DigPlan::try_from("R 2 (#000020)\nL 2 (#000022)\n").is_ok());
+    }
+}
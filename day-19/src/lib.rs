@@ -0,0 +1,559 @@
+use common::{
+    generate::{Generate, Random},
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+use std::{
+    collections::{BTreeMap, HashSet},
+    ops::Range,
+};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Category {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl Category {
+    pub const ALL: [Category; 4] = [Category::X, Category::M, Category::A, Category::S];
+
+    fn index(&self) -> usize {
+        *self as usize
+    }
+}
+
+impl TryFrom<&str> for Category {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "x" => Ok(Category::X),
+            "m" => Ok(Category::M),
+            "a" => Ok(Category::A),
+            "s" => Ok(Category::S),
+            _ => Err(ParseError::new(1, "`x`, `m`, `a` or `s`")),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Part {
+    pub ratings: [u64; 4],
+}
+
+impl Part {
+    pub fn rating(&self, category: Category) -> u64 {
+        self.ratings[category.index()]
+    }
+
+    pub fn total_rating(&self) -> u64 {
+        self.ratings.iter().sum()
+    }
+}
+
+impl TryFrom<&str> for Part {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let ratings = line
+            .strip_prefix("{")
+            .and_then(|ratings| ratings.strip_suffix("}"))
+            .ok_or_else(|| ParseError::new(1, "ratings like `{x=787,m=2655,a=1222,s=2876}`"))?;
+        let mut parts = ratings.split(',');
+        let mut result = [0; 4];
+        for category in Category::ALL {
+            let name = ["x", "m", "a", "s"][category.index()];
+            let rating = parts
+                .next()
+                .ok_or_else(|| ParseError::at(line, &line[line.len() - 1..], "a rating"))?;
+            let value = rating
+                .strip_prefix(name)
+                .and_then(|rating| rating.strip_prefix("="))
+                .ok_or_else(|| ParseError::at(line, rating, format!("`{}=`", name)))?;
+            result[category.index()] = parse::number(line, value)?;
+        }
+        if let Some(rating) = parts.next() {
+            return Err(ParseError::at(line, rating, "the end of the ratings"));
+        }
+        Ok(Self { ratings: result })
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Target {
+    Accept,
+    Reject,
+    Workflow(String),
+}
+
+impl From<&str> for Target {
+    fn from(value: &str) -> Self {
+        match value {
+            "A" => Target::Accept,
+            "R" => Target::Reject,
+            name => Target::Workflow(name.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Condition {
+    pub category: Category,
+    pub greater: bool,
+    pub value: u64,
+}
+
+impl Condition {
+    pub fn matches(&self, part: &Part) -> bool {
+        let rating = part.rating(self.category);
+        if self.greater {
+            rating > self.value
+        } else {
+            rating < self.value
+        }
+    }
+
+    // splits a range of ratings into the ratings matching the condition and the others
+    fn split(&self, range: &Range<u64>) -> (Range<u64>, Range<u64>) {
+        let threshold = if self.greater {
+            self.value + 1
+        } else {
+            self.value
+        };
+        let threshold = threshold.clamp(range.start, range.end);
+        let (below, above) = (range.start..threshold, threshold..range.end);
+        if self.greater {
+            (above, below)
+        } else {
+            (below, above)
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Rule {
+    pub condition: Option<Condition>,
+    pub target: Target,
+}
+
+impl TryFrom<&str> for Rule {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let Some((condition, target)) = value.split_once(':') else {
+            return Ok(Self {
+                condition: None,
+                target: value.into(),
+            });
+        };
+        let Some(index) = condition.find(['<', '>']) else {
+            return Err(ParseError::new(1, "a condition like `a<2006`"));
+        };
+        let category = Category::try_from(&condition[..index])?;
+        let greater = &condition[index..index + 1] == ">";
+        let value = parse::number(value, &condition[index + 1..])?;
+        Ok(Self {
+            condition: Some(Condition {
+                category,
+                greater,
+                value,
+            }),
+            target: target.into(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Workflow {
+    pub name: String,
+    pub rules: Vec<Rule>,
+}
+
+impl Workflow {
+    pub fn target(&self, part: &Part) -> &Target {
+        self.rules
+            .iter()
+            .find(|rule| rule.condition.as_ref().is_none_or(|c| c.matches(part)))
+            .map(|rule| &rule.target)
+            .expect("the last rule of a workflow has no condition")
+    }
+}
+
+impl TryFrom<&str> for Workflow {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let (name, rules) = line
+            .split_once('{')
+            .ok_or_else(|| ParseError::after(line, "`{`"))?;
+        let rules = rules
+            .strip_suffix('}')
+            .ok_or_else(|| ParseError::after(line, "`}`"))?;
+        let texts: Vec<&str> = rules.split(',').collect();
+        let rules = texts
+            .iter()
+            .map(|&rule| {
+                Rule::try_from(rule).map_err(|error| error.offset(0, parse::column(line, rule) - 1))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        // only the last rule, and every workflow has one, applies to any part
+        for (index, (rule, text)) in rules.iter().zip(texts.iter()).enumerate() {
+            let is_last = index == rules.len() - 1;
+            if rule.condition.is_none() && !is_last {
+                let expected = "no rule after a rule without condition";
+                return Err(ParseError::at(line, texts[index + 1], expected));
+            }
+            if rule.condition.is_some() && is_last {
+                return Err(ParseError::at(line, text, "a last rule without condition"));
+            }
+        }
+        Ok(Self {
+            name: name.to_string(),
+            rules,
+        })
+    }
+}
+
+/// The ranges of ratings of a set of parts, from `start` included to `end` excluded.
+#[derive(Debug, PartialEq, Clone)]
+pub struct RatingRanges {
+    pub ranges: [Range<u64>; 4],
+}
+
+impl RatingRanges {
+    pub fn all() -> Self {
+        Self {
+            ranges: [1..4001, 1..4001, 1..4001, 1..4001],
+        }
+    }
+
+    pub fn combinations(&self) -> u64 {
+        self.ranges
+            .iter()
+            .map(|range| range.end.saturating_sub(range.start))
+            .product()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.ranges.iter().any(|range| range.is_empty())
+    }
+
+    // splits the ranges into the ratings matching the condition and the others
+    fn split(&self, condition: &Condition) -> (Self, Self) {
+        let index = condition.category.index();
+        let (matching, others) = condition.split(&self.ranges[index]);
+        let (mut matching_ranges, mut other_ranges) = (self.clone(), self.clone());
+        matching_ranges.ranges[index] = matching;
+        other_ranges.ranges[index] = others;
+        (matching_ranges, other_ranges)
+    }
+}
+
+pub struct System {
+    pub workflows: BTreeMap<String, Workflow>,
+    pub parts: Vec<Part>,
+}
+
+impl System {
+    pub fn is_accepted(&self, part: &Part) -> bool {
+        let mut workflow = &self.workflows["in"];
+        loop {
+            match workflow.target(part) {
+                Target::Accept => return true,
+                Target::Reject => return false,
+                Target::Workflow(name) => workflow = &self.workflows[name],
+            }
+        }
+    }
+
+    pub fn sum_of_accepted_ratings(&self) -> u64 {
+        self.parts
+            .iter()
+            .filter(|part| self.is_accepted(part))
+            .map(Part::total_rating)
+            .sum()
+    }
+
+    /// The disjoint ranges of ratings accepted by the workflows, found by splitting the whole
+    /// range of ratings along the rules.
+    pub fn accepted_ranges(&self) -> Vec<RatingRanges> {
+        let mut accepted = vec![];
+        let mut to_visit = vec![(RatingRanges::all(), "in")];
+        while let Some((mut ranges, name)) = to_visit.pop() {
+            for rule in self.workflows[name].rules.iter() {
+                let matching = match &rule.condition {
+                    Some(condition) => {
+                        let (matching, others) = ranges.split(condition);
+                        ranges = others;
+                        matching
+                    }
+                    None => ranges.clone(),
+                };
+                if !matching.is_empty() {
+                    match &rule.target {
+                        Target::Accept => accepted.push(matching),
+                        Target::Reject => (),
+                        Target::Workflow(name) => to_visit.push((matching, name)),
+                    }
+                }
+                if ranges.is_empty() {
+                    break;
+                }
+            }
+        }
+        accepted
+    }
+
+    pub fn count_accepted_combinations(&self) -> u64 {
+        self.accepted_ranges()
+            .iter()
+            .map(RatingRanges::combinations)
+            .sum()
+    }
+}
+
+impl TryFrom<&str> for System {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut lines = input.lines().enumerate();
+        let mut workflows = BTreeMap::new();
+        let mut workflow_lines = BTreeMap::new();
+        let mut references = vec![];
+        for (index, line) in lines.by_ref().take_while(|(_, line)| !line.is_empty()) {
+            let workflow = Workflow::try_from(line).map_err(|error| error.on_line(index))?;
+            for rule in workflow.rules.iter() {
+                if let Target::Workflow(name) = &rule.target {
+                    references.push((index, line, name.clone()));
+                }
+            }
+            workflow_lines.insert(workflow.name.clone(), (index, line));
+            workflows.insert(workflow.name.clone(), workflow);
+        }
+        if !workflows.contains_key("in") {
+            return Err(ParseError::new(1, "a workflow named `in`"));
+        }
+        // every rule has to lead somewhere
+        if let Some((index, line, name)) = references
+            .iter()
+            .find(|(_, _, name)| !workflows.contains_key(name))
+        {
+            let column = line.rfind(name.as_str()).unwrap_or_default() + 1;
+            let expected = format!("a workflow named `{}`", name);
+            return Err(ParseError::new(column, expected).on_line(*index));
+        }
+        // a part sent back to a workflow it went through would never leave
+        let found = find_loop(&workflows, "in", &mut vec![], &mut HashSet::new());
+        if let Some((name, target)) = found {
+            let (index, line) = workflow_lines[name];
+            let column = line.rfind(target).unwrap_or_default() + 1;
+            let expected = format!("no rule sending parts back to `{}`", target);
+            return Err(ParseError::new(column, expected).on_line(index));
+        }
+        let parts = lines
+            .map(|(index, line)| Part::try_from(line).map_err(|error| error.on_line(index)))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { workflows, parts })
+    }
+}
+
+/// The first rule found, in `name` or the workflows after it, sending parts back to a workflow
+/// they went through on `path`, as the names of the workflow of the rule and of its target.
+fn find_loop<'a>(
+    workflows: &'a BTreeMap<String, Workflow>,
+    name: &'a str,
+    path: &mut Vec<&'a str>,
+    finished: &mut HashSet<&'a str>,
+) -> Option<(&'a str, &'a str)> {
+    path.push(name);
+    for rule in workflows[name].rules.iter() {
+        let Target::Workflow(target) = &rule.target else {
+            continue;
+        };
+        if path.contains(&target.as_str()) {
+            return Some((name, target));
+        }
+        if !finished.contains(target.as_str()) {
+            if let Some(found) = find_loop(workflows, target, path, finished) {
+                return Some(found);
+            }
+        }
+    }
+    path.pop();
+    finished.insert(name);
+    None
+}
+
+impl Solution for System {
+    const DAY: u8 = 19;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        System::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        self.sum_of_accepted_ratings().into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.count_accepted_combinations().into()
+    }
+}
+
+/// Workflows starting from `in`, each only sending parts to the workflows after it so that
+/// every part ends accepted or rejected, and parts rated from 1 to 4000. `size` is the number
+/// of workflows and of parts.
+impl Generate for System {
+    fn generate(random: &mut Random, size: usize) -> String {
+        let count = size.max(1);
+        let mut names = vec!["in".to_string()];
+        while names.len() < count {
+            let length = random.size(2..=3);
+            let name = random.string(length, "abcdefghijklmnopqrstuvwxyz");
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        let mut input = String::new();
+        for index in 0..count {
+            let mut targets: Vec<&str> = names[index + 1..].iter().map(String::as_str).collect();
+            targets.extend(["A", "R"]);
+            let mut rules: Vec<String> = (0..random.size(1..=4))
+                .map(|_| {
+                    format!(
+                        "{}{}{}:{}",
+                        random.choose(&['x', 'm', 'a', 's']),
+                        random.choose(&['<', '>']),
+                        random.number(1..=4000),
+                        random.choose(&targets)
+                    )
+                })
+                .collect();
+            rules.push(random.choose(&targets).to_string());
+            input.push_str(&format!("{}{{{}}}\n", names[index], rules.join(",")));
+        }
+        input.push('\n');
+        for _ in 0..count {
+            let [x, m, a, s] = [(); 4].map(|_| random.number(1..=4000));
+            input.push_str(&format!("{{x={},m={},a={},s={}}}\n", x, m, a, s));
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Category, Condition, Part, RatingRanges, Rule, System, Target, Workflow};
+
+    #[test]
+    fn parse_workflow() {
+        let workflow = Workflow::try_from("px{a<2006:qkq,m>2090:A,rfg}").unwrap();
+        assert_eq!(workflow.name, "px");
+        assert_eq!(
+            workflow.rules,
+            vec![
+                Rule {
+                    condition: Some(Condition {
+                        category: Category::A,
+                        greater: false,
+                        value: 2006
+                    }),
+                    target: Target::Workflow("qkq".to_string())
+                },
+                Rule {
+                    condition: Some(Condition {
+                        category: Category::M,
+                        greater: true,
+                        value: 2090
+                    }),
+                    target: Target::Accept
+                },
+                Rule {
+                    condition: None,
+                    target: Target::Workflow("rfg".to_string())
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_part() {
+        let part = Part::try_from("{x=787,m=2655,a=1222,s=2876}").unwrap();
+        assert_eq!(part.ratings, [787, 2655, 1222, 2876]);
+        assert_eq!(part.total_rating(), 7540);
+    }
+
+    #[test]
+    fn accepted_parts() {
+        let input = include_str!("../../fixtures/day-19-test");
+        let system = System::try_from(input).unwrap();
+        let accepted: Vec<bool> = system
+            .parts
+            .iter()
+            .map(|part| system.is_accepted(part))
+            .collect();
+        assert_eq!(accepted, vec![true, false, true, false, true]);
+    }
+
+    #[test]
+    fn split_ranges() {
+        let condition = Condition {
+            category: Category::M,
+            greater: true,
+            value: 2090,
+        };
+        let (matching, others) = RatingRanges::all().split(&condition);
+        assert_eq!(matching.ranges[1], 2091..4001);
+        assert_eq!(others.ranges[1], 1..2091);
+        assert_eq!(
+            matching.combinations() + others.combinations(),
+            4000_u64.pow(4)
+        );
+    }
+
+    #[test]
+    fn accepted_ranges() {
+        let system = System::try_from("in{x<2001:A,m>3000:R,A}\n\n{x=1,m=1,a=1,s=1}").unwrap();
+        let accepted = system.accepted_ranges();
+        assert_eq!(accepted.len(), 2);
+        assert!(accepted.contains(&RatingRanges {
+            ranges: [1..2001, 1..4001, 1..4001, 1..4001]
+        }));
+        assert!(accepted.contains(&RatingRanges {
+            ranges: [2001..4001, 1..3001, 1..4001, 1..4001]
+        }));
+    }
+
+    #[test]
+    fn invalid_system() {
+        let error = System::try_from("in{s<1351:px,R}\n\n").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 11));
+        let error = System::try_from("in{s<x:A,R}").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 6));
+        let error = System::try_from("in{y<3:A,R}").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 4));
+        let error = System::try_from("in{s<3:A}").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 4));
+        let error = System::try_from("in{A,R}").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 6));
+        let error = System::try_from("in{A}\n\n{x=1,m=2,s=3,a=4}")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (3, 10));
+    }
+
+    #[test]
+    fn looping_workflows() {
+        let error = System::try_from("in{a<5:in,A}\n\n{x=1,m=2,a=3,s=4}")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (1, 8));
+        let input = "in{a<5:px,qs}\npx{m>2:qs,R}\nqs{x>3:A,s<2:px,R}\n\n{x=1,m=2,a=3,s=4}";
+        let error = System::try_from(input).err().unwrap();
+        assert_eq!((error.line, error.column), (3, 14));
+        // two workflows sending parts to the same one do not make a loop
+        assert!(System::try_from("in{a<5:px,qs}\npx{m>2:qs,A}\nqs{A}\n\n").is_ok());
+    }
+}
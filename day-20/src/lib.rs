@@ -0,0 +1,384 @@
+use common::{
+    generate::{Generate, Random},
+    parse::ParseError,
+    solution::{Answer, Solution},
+};
+use std::{
+    collections::{BTreeMap, VecDeque},
+    fmt::Display,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Pulse {
+    Low,
+    High,
+}
+
+/// A pulse sent from a module to another one.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Event {
+    pub from: String,
+    pub pulse: Pulse,
+    pub to: String,
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let pulse = match self.pulse {
+            Pulse::Low => "low",
+            Pulse::High => "high",
+        };
+        write!(f, "{} -{}-> {}", self.from, pulse, self.to)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ModuleKind {
+    Broadcaster,
+    FlipFlop { on: bool },
+    // the last pulse received from each input
+    Conjunction { inputs: BTreeMap<String, Pulse> },
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Module {
+    pub name: String,
+    pub kind: ModuleKind,
+    pub outputs: Vec<String>,
+}
+
+impl Module {
+    // the pulse sent to every output when receiving a pulse, if any
+    fn receive(&mut self, from: &str, pulse: Pulse) -> Option<Pulse> {
+        match (&mut self.kind, pulse) {
+            (ModuleKind::Broadcaster, pulse) => Some(pulse),
+            (ModuleKind::FlipFlop { .. }, Pulse::High) => None,
+            (ModuleKind::FlipFlop { on }, Pulse::Low) => {
+                *on = !*on;
+                Some(if *on { Pulse::High } else { Pulse::Low })
+            }
+            (ModuleKind::Conjunction { inputs }, pulse) => {
+                inputs.insert(from.to_string(), pulse);
+                if inputs.values().all(|&pulse| pulse == Pulse::High) {
+                    Some(Pulse::Low)
+                } else {
+                    Some(Pulse::High)
+                }
+            }
+        }
+    }
+}
+
+impl TryFrom<&str> for Module {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let (name, outputs) = line
+            .split_once(" -> ")
+            .ok_or_else(|| ParseError::after(line, "` -> `"))?;
+        let (kind, name) = if let Some(name) = name.strip_prefix('%') {
+            (ModuleKind::FlipFlop { on: false }, name)
+        } else if let Some(name) = name.strip_prefix('&') {
+            let inputs = BTreeMap::new();
+            (ModuleKind::Conjunction { inputs }, name)
+        } else if name == "broadcaster" {
+            (ModuleKind::Broadcaster, name)
+        } else {
+            return Err(ParseError::new(1, "`%`, `&` or `broadcaster`"));
+        };
+        if name.is_empty() {
+            return Err(ParseError::new(2, "a module name"));
+        }
+        let outputs = outputs
+            .split(", ")
+            .map(|output| output.to_string())
+            .collect();
+        Ok(Self {
+            name: name.to_string(),
+            kind,
+            outputs,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Network {
+    pub modules: BTreeMap<String, Module>,
+}
+
+impl Network {
+    /// Pushes the button once, returning every pulse sent, in the order they were processed.
+    pub fn press(&mut self) -> Vec<Event> {
+        let mut trace = vec![];
+        let mut queue = VecDeque::from([Event {
+            from: "button".to_string(),
+            pulse: Pulse::Low,
+            to: "broadcaster".to_string(),
+        }]);
+        while let Some(event) = queue.pop_front() {
+            // modules without a definition, like `rx`, only receive pulses
+            if let Some(module) = self.modules.get_mut(&event.to) {
+                if let Some(pulse) = module.receive(&event.from, event.pulse) {
+                    queue.extend(module.outputs.iter().map(|output| Event {
+                        from: module.name.clone(),
+                        pulse,
+                        to: output.clone(),
+                    }));
+                }
+            }
+            trace.push(event);
+        }
+        trace
+    }
+
+    /// The number of low and high pulses sent while pushing the button `presses` times.
+    pub fn count_pulses(&mut self, presses: usize) -> (usize, usize) {
+        let (mut low, mut high) = (0, 0);
+        for _ in 0..presses {
+            for event in self.press() {
+                match event.pulse {
+                    Pulse::Low => low += 1,
+                    Pulse::High => high += 1,
+                }
+            }
+        }
+        (low, high)
+    }
+
+    pub fn pulses_product(&self) -> usize {
+        let (low, high) = self.clone().count_pulses(1000);
+        low * high
+    }
+
+    /// The number of presses until `target` receives a low pulse.
+    ///
+    /// The target has to be fed by a single conjunction, which sends a low pulse when all its
+    /// inputs have just sent it a high one. Each input does so periodically, so the answer is
+    /// the least common multiple of the press at which each input first sends a high pulse.
+    pub fn presses_until_low_pulse(&self, target: &str) -> Option<usize> {
+        let mut feeders = self
+            .modules
+            .values()
+            .filter(|module| module.outputs.iter().any(|output| output == target));
+        let feeder = feeders.next()?;
+        let ModuleKind::Conjunction { inputs } = &feeder.kind else {
+            return None;
+        };
+        if feeders.next().is_some() {
+            return None;
+        }
+
+        let mut cycles: BTreeMap<&str, usize> = BTreeMap::new();
+        let mut network = self.clone();
+        let mut presses = 0;
+        while cycles.len() < inputs.len() {
+            presses += 1;
+            for event in network.press() {
+                if event.to == feeder.name && event.pulse == Pulse::High {
+                    let input = inputs.get_key_value(&event.from).expect("an input").0;
+                    cycles.entry(input).or_insert(presses);
+                }
+            }
+            // inputs that never send a high pulse would make this loop forever
+            if presses > 1_000_000 {
+                return None;
+            }
+        }
+        Some(
+            cycles
+                .values()
+                .fold(1, |lcm, &cycle| num::integer::lcm(lcm, cycle)),
+        )
+    }
+}
+
+impl TryFrom<&str> for Network {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut modules = BTreeMap::new();
+        for (index, line) in input.lines().enumerate() {
+            let module = Module::try_from(line).map_err(|error| error.on_line(index))?;
+            modules.insert(module.name.clone(), module);
+        }
+        if !modules.contains_key("broadcaster") {
+            return Err(ParseError::new(1, "a `broadcaster` module"));
+        }
+        // conjunctions remember a low pulse from each of their inputs at first
+        let connections: Vec<(String, String)> = modules
+            .values()
+            .flat_map(|module| {
+                module
+                    .outputs
+                    .iter()
+                    .map(|output| (module.name.clone(), output.clone()))
+            })
+            .collect();
+        for (from, to) in connections {
+            if let Some(ModuleKind::Conjunction { inputs }) =
+                modules.get_mut(&to).map(|module| &mut module.kind)
+            {
+                inputs.insert(from, Pulse::Low);
+            }
+        }
+        Ok(Self { modules })
+    }
+}
+
+impl Solution for Network {
+    const DAY: u8 = 20;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Network::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        self.pulses_product().into()
+    }
+
+    fn part2(&self) -> Answer {
+        match self.presses_until_low_pulse("rx") {
+            Some(presses) => presses.into(),
+            None => "no single conjunction feeding `rx`".into(),
+        }
+    }
+}
+
+/// A network built like the puzzle's: the broadcaster feeds 4 binary counters of flip-flops,
+/// each resetting itself through a conjunction when it reaches a random number of presses, and
+/// the inverted conjunctions feed a single conjunction sending to `rx`. `size` is the number of
+/// bits of the counters, from 2 to 12.
+impl Generate for Network {
+    fn generate(random: &mut Random, size: usize) -> String {
+        let bits = size.clamp(2, 12);
+        let mut names: Vec<String> = (0..26 * 26)
+            .map(|index| {
+                let letters = [b'a' + (index / 26) as u8, b'a' + (index % 26) as u8];
+                String::from_utf8(letters.to_vec()).expect("ASCII letters")
+            })
+            .filter(|name| name != "rx")
+            .collect();
+        random.shuffle(&mut names);
+        let mut names = names.into_iter();
+        let mut name = || names.next().expect("enough names");
+        let feed = name();
+        let mut lines = vec![format!("&{} -> rx", feed)];
+        let mut starts = vec![];
+        for _ in 0..4 {
+            // the counter resets at `presses`, an odd number using every bit
+            let presses = random.number(1 << (bits - 1)..=(1 << bits) - 1) | 1;
+            let flip_flops: Vec<String> = (0..bits).map(|_| name()).collect();
+            let (hub, inverter) = (name(), name());
+            let mut hub_outputs = vec![inverter.clone()];
+            for (bit, flip_flop) in flip_flops.iter().enumerate() {
+                let mut outputs = flip_flops
+                    .get(bit + 1)
+                    .into_iter()
+                    .cloned()
+                    .collect::<Vec<_>>();
+                if presses & (1 << bit) != 0 {
+                    outputs.push(hub.clone());
+                }
+                if bit == 0 || presses & (1 << bit) == 0 {
+                    hub_outputs.push(flip_flop.clone());
+                }
+                lines.push(format!("%{} -> {}", flip_flop, outputs.join(", ")));
+            }
+            lines.push(format!("&{} -> {}", hub, hub_outputs.join(", ")));
+            lines.push(format!("&{} -> {}", inverter, feed));
+            starts.push(flip_flops[0].clone());
+        }
+        lines.push(format!("broadcaster -> {}", starts.join(", ")));
+        random.shuffle(&mut lines);
+        lines.join("\n") + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::generate::{Generate, Random};
+
+    use crate::{Network, Pulse};
+
+    const FEEDING_RX: &str = "broadcaster -> a\n%a -> b, ia\n%b -> ib\n\
+                              &ia -> feed\n&ib -> feed\n&feed -> rx";
+
+    #[test]
+    fn trace() {
+        let input = include_str!("../../fixtures/day-20-test2");
+        let mut network = Network::try_from(input).unwrap();
+        let trace: Vec<String> = network.press().iter().map(|e| e.to_string()).collect();
+        assert_eq!(
+            trace,
+            vec![
+                "button -low-> broadcaster",
+                "broadcaster -low-> a",
+                "a -high-> inv",
+                "a -high-> con",
+                "inv -low-> b",
+                "con -high-> output",
+                "b -high-> con",
+                "con -low-> output",
+            ]
+        );
+        let second = network.press();
+        assert_eq!(second.len(), 6);
+        assert_eq!(second[5].to_string(), "con -high-> output");
+    }
+
+    #[test]
+    fn count_pulses() {
+        let input = include_str!("../../fixtures/day-20-test");
+        let mut network = Network::try_from(input).unwrap();
+        assert_eq!(network.count_pulses(1), (8, 4));
+    }
+
+    #[test]
+    fn presses_until_low_pulse() {
+        let network = Network::try_from(FEEDING_RX).unwrap();
+        assert_eq!(network.presses_until_low_pulse("rx"), Some(4));
+        // pressing the button that many times does send a low pulse to `rx`
+        let mut network = network.clone();
+        let presses = (1..)
+            .find(|_| {
+                network
+                    .press()
+                    .iter()
+                    .any(|event| event.to == "rx" && event.pulse == Pulse::Low)
+            })
+            .unwrap();
+        assert_eq!(presses, 4);
+        let input = include_str!("../../fixtures/day-20-test");
+        let network = Network::try_from(input).unwrap();
+        assert_eq!(network.presses_until_low_pulse("rx"), None);
+    }
+
+    #[test]
+    fn invalid_network() {
+        let error = Network::try_from("broadcaster -> a\n%a > b").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 7));
+        let error = Network::try_from("broadcaster -> a\na -> b").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+        let error = Network::try_from("%a -> b").err().unwrap();
+        assert_eq!(error.expected, "a `broadcaster` module");
+    }
+
+    #[test]
+    fn generated_networks() {
+        for seed in 0..3 {
+            let input = Network::generate(&mut Random::new(seed), 3);
+            let network = Network::try_from(input.as_str()).unwrap();
+            // pressing until `rx` gets a low pulse agrees with the cycles of the counters
+            let mut pressed = network.clone();
+            let presses = (1..)
+                .find(|_| {
+                    pressed
+                        .press()
+                        .iter()
+                        .any(|event| event.to == "rx" && event.pulse == Pulse::Low)
+                })
+                .unwrap();
+            assert_eq!(network.presses_until_low_pulse("rx"), Some(presses));
+        }
+    }
+}
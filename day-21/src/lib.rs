@@ -0,0 +1,220 @@
+use common::{
+    generate::{Generate, Random},
+    parse::ParseError,
+    solution::{Answer, Solution},
+};
+use grid::{Grid, Position};
+use std::collections::{HashSet, VecDeque};
+
+pub struct Garden {
+    pub rocks: Grid<bool>,
+    pub start: Position,
+}
+
+impl Garden {
+    /// The number of plots the elf can stand on after exactly `steps` steps, within the map.
+    ///
+    /// A plot reached in fewer steps can still be reached at the end by going back and forth,
+    /// as long as the parity of the distance matches.
+    pub fn reachable(&self, steps: usize) -> usize {
+        let mut distances = Grid::filled(self.rocks.width(), self.rocks.height(), None);
+        distances[self.start] = Some(0);
+        let mut queue = VecDeque::from([self.start]);
+        while let Some(position) = queue.pop_front() {
+            let distance = distances[position].expect("queued positions have a distance");
+            if distance == steps {
+                continue;
+            }
+            for neighbour in self.rocks.neighbours4(position) {
+                if !self.rocks[neighbour] && distances[neighbour].is_none() {
+                    distances[neighbour] = Some(distance + 1);
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+        distances
+            .cells()
+            .flatten()
+            .filter(|&distance| distance % 2 == steps % 2)
+            .count()
+    }
+
+    /// Same as [`Garden::reachable`] on the map repeated infinitely in every direction, by
+    /// walking every step: only usable for a small number of steps.
+    pub fn reachable_infinite(&self, steps: usize) -> usize {
+        let (width, height) = (self.rocks.width() as i64, self.rocks.height() as i64);
+        let is_rock = |(x, y): (i64, i64)| {
+            self.rocks[(x.rem_euclid(width) as usize, y.rem_euclid(height) as usize)]
+        };
+        let start = (self.start.0 as i64, self.start.1 as i64);
+        let mut visited = HashSet::from([start]);
+        let mut frontier = vec![start];
+        let mut counts = [1, 0];
+        for step in 1..=steps {
+            frontier = frontier
+                .iter()
+                .flat_map(|&(x, y)| [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)])
+                .filter(|&position| !is_rock(position) && visited.insert(position))
+                .collect();
+            counts[step % 2] += frontier.len();
+        }
+        counts[steps % 2]
+    }
+
+    /// Same as [`Garden::reachable_infinite`], for a number of steps far too big to walk.
+    ///
+    /// On a square map with the start in its middle, when the row and the column of the start
+    /// are free of rocks, the steps reach a new ring of copies of the map every `size` steps.
+    /// The number of reachable plots then grows quadratically with the number of rings, so
+    /// three walked values give the others. `steps` has to be `size / 2` more than a multiple
+    /// of `size`, which brings the walk to the edge of the last ring.
+    pub fn reachable_extrapolated(&self, steps: usize) -> Option<usize> {
+        let size = self.rocks.width();
+        let half = size / 2;
+        let is_clear = self.rocks.row(half).iter().all(|&rock| !rock)
+            && self.rocks.column(half).all(|&rock| !rock);
+        if size != self.rocks.height() || self.start != (half, half) || !is_clear {
+            return None;
+        }
+        if steps % size != half {
+            return None;
+        }
+        let rings = steps / size;
+        if rings < 3 {
+            return Some(self.reachable_infinite(steps));
+        }
+        let [a0, a1, a2] = [0, 1, 2].map(|ring| self.reachable_infinite(half + ring * size));
+        // Newton's forward differences of a quadratic
+        let first = a1 - a0;
+        let second = a2 + a0 - 2 * a1;
+        Some(a0 + rings * first + rings * (rings - 1) / 2 * second)
+    }
+}
+
+impl TryFrom<&str> for Garden {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let tiles = Grid::parse(input, |c| match c {
+            '.' | '#' | 'S' => Ok(c),
+            _ => Err(ParseError::new(1, "`.`, `#` or `S`")),
+        })?;
+        let starts: Vec<Position> = tiles
+            .iter()
+            .filter(|(_, &c)| c == 'S')
+            .map(|(position, _)| position)
+            .collect();
+        let start = match starts[..] {
+            [] => return Err(ParseError::new(1, "a starting point `S`")),
+            [start] => start,
+            [_, (x, y), ..] => {
+                let error = ParseError::new(x + 1, "a single starting point `S`");
+                return Err(error.on_line(y));
+            }
+        };
+        Ok(Self {
+            rocks: tiles.map(|&c| c == '#'),
+            start,
+        })
+    }
+}
+
+impl Solution for Garden {
+    const DAY: u8 = 21;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Garden::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        self.reachable(64).into()
+    }
+
+    fn part2(&self) -> Answer {
+        match self.reachable_extrapolated(26501365) {
+            Some(plots) => plots.into(),
+            None => "the map cannot be extrapolated".into(),
+        }
+    }
+}
+
+/// A square garden of side `2 * size + 1` with the start in its middle, its row and column free
+/// of rocks. With a `size` of 65, the side of the puzzle's garden, 26501365 steps bring the
+/// walk to the edge of a ring of copies of the map and part 2 can be extrapolated.
+impl Generate for Garden {
+    fn generate(random: &mut Random, size: usize) -> String {
+        let half = size.max(1);
+        let side = 2 * half + 1;
+        let mut tiles = Grid::filled(side, side, '.');
+        for (x, y) in tiles.positions().collect::<Vec<_>>() {
+            if x != half && y != half && random.chance(15) {
+                tiles[(x, y)] = '#';
+            }
+        }
+        tiles[(half, half)] = 'S';
+        tiles.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::solution::{Answer, Solution};
+
+    use crate::Garden;
+
+    // the row and column of the start are clear, unlike in the example
+    const CLEAR_CROSS: &str = ".....\n.#.#.\n..S..\n.#.#.\n.....";
+
+    #[test]
+    fn reachable() {
+        let input = include_str!("../../fixtures/day-21-test");
+        let garden = Garden::try_from(input).unwrap();
+        assert_eq!(garden.start, (5, 5));
+        assert_eq!(garden.reachable(1), 2);
+        assert_eq!(garden.reachable(6), 16);
+    }
+
+    #[test]
+    fn reachable_infinite() {
+        let input = include_str!("../../fixtures/day-21-test");
+        let garden = Garden::try_from(input).unwrap();
+        assert_eq!(garden.reachable_infinite(6), 16);
+        assert_eq!(garden.reachable_infinite(10), 50);
+        assert_eq!(garden.reachable_infinite(50), 1594);
+        assert_eq!(garden.reachable_infinite(100), 6536);
+    }
+
+    #[test]
+    fn reachable_extrapolated() {
+        let garden = Garden::try_from(".....\n.....\n..S..\n.....\n.....").unwrap();
+        assert_eq!(garden.reachable_extrapolated(52), Some(53 * 53));
+        let garden = Garden::try_from(CLEAR_CROSS).unwrap();
+        for steps in [2, 17, 32, 47] {
+            assert_eq!(
+                garden.reachable_extrapolated(steps),
+                Some(garden.reachable_infinite(steps))
+            );
+        }
+        assert_eq!(garden.reachable_extrapolated(30), None);
+        let input = include_str!("../../fixtures/day-21-test");
+        let garden = Garden::try_from(input).unwrap();
+        assert_eq!(garden.reachable_extrapolated(5 + 11 * 4), None);
+    }
+
+    #[test]
+    fn invalid_gardens() {
+        let error = Garden::try_from("...\n.S.\n..x").err().unwrap();
+        assert_eq!((error.line, error.column), (3, 3));
+        let error = Garden::try_from("...\n.S.\n..S").err().unwrap();
+        assert_eq!((error.line, error.column), (3, 3));
+        let error = Garden::try_from("...\n...").err().unwrap();
+        assert_eq!(error.expected, "a starting point `S`");
+    }
+
+    #[test]
+    fn part2_without_rocks() {
+        // without rocks, the plots at an even distance from the start within 26501365 steps
+        let solution = Garden::parse("...\n.S.\n...").unwrap();
+        assert_eq!(solution.part2(), Answer::Unsigned(26501366 * 26501366));
+    }
+}
@@ -0,0 +1,279 @@
+use common::{
+    generate::{Generate, Random},
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+use grid::Grid;
+use std::collections::VecDeque;
+
+/// A brick, from its `start` to its `end` cube included, `start` having the lowest coordinates.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Brick {
+    pub start: [usize; 3],
+    pub end: [usize; 3],
+}
+
+impl Brick {
+    pub fn bottom(&self) -> usize {
+        self.start[2]
+    }
+
+    pub fn height(&self) -> usize {
+        self.end[2] - self.start[2] + 1
+    }
+
+    // the columns the brick covers when seen from above
+    fn footprint(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (self.start[1]..=self.end[1])
+            .flat_map(move |y| (self.start[0]..=self.end[0]).map(move |x| (x, y)))
+    }
+
+    fn moved_to(&self, bottom: usize) -> Self {
+        let mut brick = self.clone();
+        brick.start[2] = bottom;
+        brick.end[2] = bottom + self.height() - 1;
+        brick
+    }
+}
+
+impl TryFrom<&str> for Brick {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let (start, end) = line
+            .split_once('~')
+            .ok_or_else(|| ParseError::after(line, "`~`"))?;
+        let coordinates = |text: &str| -> Result<[usize; 3], ParseError> {
+            let coordinates = text
+                .split(',')
+                .map(|coordinate| parse::number(line, coordinate))
+                .collect::<Result<Vec<usize>, _>>()?;
+            coordinates
+                .try_into()
+                .map_err(|_| ParseError::at(line, text, "three coordinates `x,y,z`"))
+        };
+        let (start, end) = (coordinates(start)?, coordinates(end)?);
+        if start[2] == 0 || end[2] == 0 {
+            return Err(ParseError::new(
+                1,
+                "a brick above the ground, at `z` 1 or more",
+            ));
+        }
+        Ok(Self {
+            start: [0, 1, 2].map(|axis| start[axis].min(end[axis])),
+            end: [0, 1, 2].map(|axis| start[axis].max(end[axis])),
+        })
+    }
+}
+
+/// The bricks once they have all fallen, and which ones rest on which.
+pub struct Stack {
+    pub bricks: Vec<Brick>,
+    // the bricks resting on each brick
+    pub supports: Vec<Vec<usize>>,
+    // the bricks each brick rests on
+    pub supported_by: Vec<Vec<usize>>,
+}
+
+impl Stack {
+    /// Lets the bricks fall, lowest first, keeping for each column the top of the pile and
+    /// the brick at that top.
+    pub fn settle(mut bricks: Vec<Brick>) -> Self {
+        bricks.sort_by_key(Brick::bottom);
+        let width = bricks
+            .iter()
+            .map(|brick| brick.end[0] + 1)
+            .max()
+            .unwrap_or(0);
+        let depth = bricks
+            .iter()
+            .map(|brick| brick.end[1] + 1)
+            .max()
+            .unwrap_or(0);
+        let mut heights: Grid<(usize, Option<usize>)> = Grid::filled(width, depth, (0, None));
+        let mut settled: Vec<Brick> = Vec::with_capacity(bricks.len());
+        let mut supports = vec![vec![]; bricks.len()];
+        let mut supported_by = vec![vec![]; bricks.len()];
+        for (index, brick) in bricks.iter().enumerate() {
+            let top = brick
+                .footprint()
+                .map(|column| heights[column].0)
+                .max()
+                .unwrap_or(0);
+            let mut below: Vec<usize> = brick
+                .footprint()
+                .filter_map(|column| match heights[column] {
+                    (height, Some(other)) if height == top => Some(other),
+                    _ => None,
+                })
+                .collect();
+            below.sort();
+            below.dedup();
+            for &other in below.iter() {
+                supports[other].push(index);
+            }
+            supported_by[index] = below;
+            let brick = brick.moved_to(top + 1);
+            for column in brick.footprint() {
+                heights[column] = (brick.end[2], Some(index));
+            }
+            settled.push(brick);
+        }
+        Self {
+            bricks: settled,
+            supports,
+            supported_by,
+        }
+    }
+
+    /// Whether removing the brick makes no other brick fall.
+    pub fn is_safe_to_remove(&self, index: usize) -> bool {
+        self.supports[index]
+            .iter()
+            .all(|&other| self.supported_by[other].len() > 1)
+    }
+
+    pub fn count_safe_removals(&self) -> usize {
+        (0..self.bricks.len())
+            .filter(|&index| self.is_safe_to_remove(index))
+            .count()
+    }
+
+    /// The number of other bricks falling when the brick is removed: a brick falls once every
+    /// brick it rests on has fallen.
+    pub fn count_falls(&self, index: usize) -> usize {
+        let mut remaining: Vec<usize> = self.supported_by.iter().map(Vec::len).collect();
+        let mut queue = VecDeque::from([index]);
+        let mut falls = 0;
+        while let Some(fallen) = queue.pop_front() {
+            for &other in self.supports[fallen].iter() {
+                remaining[other] -= 1;
+                if remaining[other] == 0 {
+                    falls += 1;
+                    queue.push_back(other);
+                }
+            }
+        }
+        falls
+    }
+
+    pub fn sum_of_falls(&self) -> usize {
+        (0..self.bricks.len())
+            .map(|index| self.count_falls(index))
+            .sum()
+    }
+}
+
+impl TryFrom<&str> for Stack {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let bricks = parse::lines(input, |line| Brick::try_from(line))?;
+        Ok(Stack::settle(bricks))
+    }
+}
+
+impl Solution for Stack {
+    const DAY: u8 = 22;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Stack::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        self.count_safe_removals().into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.sum_of_falls().into()
+    }
+}
+
+/// Bricks of 1 to 4 cubes in a 10 by 10 area, each floating above the previous ones so that
+/// none of them overlap, listed in a random order. `size` is the number of bricks.
+impl Generate for Stack {
+    fn generate(random: &mut Random, size: usize) -> String {
+        let mut top = 0;
+        let mut lines: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                let mut start = [
+                    random.size(0..=9),
+                    random.size(0..=9),
+                    top + random.size(1..=3),
+                ];
+                let mut end = start;
+                let axis = random.size(0..=2);
+                end[axis] += random.size(0..=3);
+                end[0] = end[0].min(9);
+                end[1] = end[1].min(9);
+                top = end[2];
+                if random.chance(50) {
+                    (start, end) = (end, start);
+                }
+                let coordinates =
+                    |cube: [usize; 3]| cube.map(|coordinate| coordinate.to_string()).join(",");
+                format!("{}~{}", coordinates(start), coordinates(end))
+            })
+            .collect();
+        random.shuffle(&mut lines);
+        lines.join("\n") + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Brick, Stack};
+
+    #[test]
+    fn parse_brick() {
+        let brick = Brick::try_from("2,2,2~0,2,2").unwrap();
+        assert_eq!(
+            brick,
+            Brick {
+                start: [0, 2, 2],
+                end: [2, 2, 2]
+            }
+        );
+        let brick = Brick::try_from("1,1,8~1,1,9").unwrap();
+        assert_eq!(brick.height(), 2);
+    }
+
+    #[test]
+    fn settle() {
+        let input = include_str!("../../fixtures/day-22-test");
+        let stack = Stack::try_from(input).unwrap();
+        let bottoms: Vec<usize> = stack.bricks.iter().map(Brick::bottom).collect();
+        assert_eq!(bottoms, vec![1, 2, 2, 3, 3, 4, 5]);
+        assert_eq!(stack.supports[0], vec![1, 2]);
+        assert_eq!(stack.supported_by[3], vec![1, 2]);
+        assert_eq!(stack.supported_by[6], vec![5]);
+    }
+
+    #[test]
+    fn safe_removals() {
+        let input = include_str!("../../fixtures/day-22-test");
+        let stack = Stack::try_from(input).unwrap();
+        assert!(!stack.is_safe_to_remove(0));
+        assert!(stack.is_safe_to_remove(1));
+    }
+
+    #[test]
+    fn falls() {
+        let input = include_str!("../../fixtures/day-22-test");
+        let stack = Stack::try_from(input).unwrap();
+        assert_eq!(stack.count_falls(0), 6);
+        assert_eq!(stack.count_falls(5), 1);
+    }
+
+    #[test]
+    fn invalid_bricks() {
+        let error = Stack::try_from("1,0,1~1,2,1\n0,0,2-2,0,2").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 12));
+        let error = Stack::try_from("1,0,1~1,2,1\n0,0,2~2,x,2").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 9));
+        let error = Stack::try_from("1,0,1~1,2\n").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 7));
+        let error = Stack::try_from("1,0,0~1,2,0").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 1));
+    }
+}
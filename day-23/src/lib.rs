@@ -0,0 +1,328 @@
+use common::{
+    generate::{Generate, Random},
+    parse::ParseError,
+    solution::{Answer, Solution},
+};
+use grid::{Direction, Grid, Position};
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Tile {
+    Path,
+    Forest,
+    Slope(Direction),
+}
+
+impl TryFrom<char> for Tile {
+    type Error = ParseError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Tile::Path),
+            '#' => Ok(Tile::Forest),
+            '^' => Ok(Tile::Slope(Direction::Up)),
+            '>' => Ok(Tile::Slope(Direction::Right)),
+            'v' => Ok(Tile::Slope(Direction::Down)),
+            '<' => Ok(Tile::Slope(Direction::Left)),
+            _ => Err(ParseError::new(1, "a tile among `.#^>v<`")),
+        }
+    }
+}
+
+/// Why no longest hike was found.
+#[derive(Debug, PartialEq, Eq)]
+pub enum NoHike {
+    Unreachable,
+    /// More junctions than the 64 bits keeping the visited ones, the count being given.
+    TooManyJunctions(usize),
+}
+
+impl Display for NoHike {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NoHike::Unreachable => write!(f, "no hike to the end"),
+            NoHike::TooManyJunctions(count) => {
+                write!(f, "{} junctions, too many to try every hike", count)
+            }
+        }
+    }
+}
+
+/// The junctions of the map, and the length of the trails going from one to another.
+pub struct Trails {
+    pub junctions: Vec<Position>,
+    // for each junction, the junctions a trail leads to, with its length
+    pub edges: Vec<Vec<(usize, usize)>>,
+}
+
+impl Trails {
+    /// The length of the longest trail from a junction to another, never visiting a junction
+    /// twice.
+    ///
+    /// Every trail is tried, the visited junctions being kept in the bits of a `u64`.
+    pub fn longest_path(&self, from: usize, to: usize) -> Result<usize, NoHike> {
+        if self.junctions.len() > 64 {
+            return Err(NoHike::TooManyJunctions(self.junctions.len()));
+        }
+        // when a single junction leads to the end, going anywhere else from it is a dead end
+        let leading_to_end: Vec<usize> = (0..self.junctions.len())
+            .filter(|&junction| self.edges[junction].iter().any(|&(next, _)| next == to))
+            .collect();
+        let last = match leading_to_end[..] {
+            [last] => Some(last),
+            _ => None,
+        };
+        self.longest_path_rec(from, to, last, 1 << from)
+            .ok_or(NoHike::Unreachable)
+    }
+
+    fn longest_path_rec(
+        &self,
+        current: usize,
+        to: usize,
+        last: Option<usize>,
+        visited: u64,
+    ) -> Option<usize> {
+        if current == to {
+            return Some(0);
+        }
+        self.edges[current]
+            .iter()
+            .filter(|&&(next, _)| Some(current) != last || next == to)
+            .filter(|&&(next, _)| visited & (1 << next) == 0)
+            .filter_map(|&(next, length)| {
+                self.longest_path_rec(next, to, last, visited | (1 << next))
+                    .map(|rest| rest + length)
+            })
+            .max()
+    }
+}
+
+pub struct HikingMap {
+    pub tiles: Grid<Tile>,
+    pub start: Position,
+    pub end: Position,
+}
+
+impl HikingMap {
+    fn is_open(&self, position: Position) -> bool {
+        self.tiles[position] != Tile::Forest
+    }
+
+    // whether leaving the position in that direction is allowed
+    fn can_leave(&self, position: Position, direction: Direction, slippery: bool) -> bool {
+        match self.tiles[position] {
+            Tile::Slope(slope) if slippery => slope == direction,
+            _ => true,
+        }
+    }
+
+    fn is_junction(&self, position: Position) -> bool {
+        position == self.start
+            || position == self.end
+            || (self.is_open(position)
+                && self
+                    .tiles
+                    .neighbours4(position)
+                    .filter(|&neighbour| self.is_open(neighbour))
+                    .count()
+                    > 2)
+    }
+
+    /// Compresses the map into the trails between junctions. On slippery slopes, the trails
+    /// can only be followed downhill.
+    pub fn trails(&self, slippery: bool) -> Trails {
+        let junctions: Vec<Position> = self
+            .tiles
+            .positions()
+            .filter(|&position| self.is_junction(position))
+            .collect();
+        let mut indices = self.tiles.map(|_| None);
+        for (index, &junction) in junctions.iter().enumerate() {
+            indices[junction] = Some(index);
+        }
+        let edges = junctions
+            .iter()
+            .map(|&junction| {
+                Direction::ALL
+                    .into_iter()
+                    .filter_map(|direction| self.follow(junction, direction, slippery))
+                    .map(|(end, length)| (indices[end].expect("trails end on junctions"), length))
+                    .collect()
+            })
+            .collect();
+        Trails { junctions, edges }
+    }
+
+    // the junction reached by leaving a junction in a direction, and the length of the trail
+    fn follow(
+        &self,
+        junction: Position,
+        direction: Direction,
+        slippery: bool,
+    ) -> Option<(Position, usize)> {
+        if !self.can_leave(junction, direction, slippery) {
+            return None;
+        }
+        let mut previous = junction;
+        let mut current = self
+            .tiles
+            .step(junction, direction)
+            .filter(|&next| self.is_open(next))?;
+        let mut length = 1;
+        while !self.is_junction(current) {
+            let (direction, next) = Direction::ALL
+                .into_iter()
+                .filter_map(|direction| Some((direction, self.tiles.step(current, direction)?)))
+                .find(|&(_, next)| next != previous && self.is_open(next))?;
+            if !self.can_leave(current, direction, slippery) {
+                return None;
+            }
+            (previous, current) = (current, next);
+            length += 1;
+        }
+        Some((current, length))
+    }
+
+    pub fn longest_hike(&self, slippery: bool) -> Result<usize, NoHike> {
+        let trails = self.trails(slippery);
+        let index = |position| {
+            trails
+                .junctions
+                .iter()
+                .position(|&junction| junction == position)
+                .expect("the start and the end are junctions")
+        };
+        trails.longest_path(index(self.start), index(self.end))
+    }
+}
+
+impl TryFrom<&str> for HikingMap {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let tiles = Grid::parse(input, Tile::try_from)?;
+        if tiles.height() < 2 {
+            return Err(ParseError::new(1, "a map of two lines or more"));
+        }
+        let opening = |y: usize| {
+            let x = tiles
+                .row(y)
+                .iter()
+                .position(|&tile| tile == Tile::Path)
+                .ok_or_else(|| ParseError::new(1, "a path `.` in the line").on_line(y))?;
+            Ok((x, y))
+        };
+        let start = opening(0)?;
+        let end = opening(tiles.height() - 1)?;
+        Ok(Self { tiles, start, end })
+    }
+}
+
+impl Solution for HikingMap {
+    const DAY: u8 = 23;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        HikingMap::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        match self.longest_hike(true) {
+            Ok(length) => length.into(),
+            Err(no_hike) => no_hike.to_string().into(),
+        }
+    }
+
+    fn part2(&self) -> Answer {
+        match self.longest_hike(false) {
+            Ok(length) => length.into(),
+            Err(no_hike) => no_hike.to_string().into(),
+        }
+    }
+}
+
+/// A map built like the puzzle's: a lattice of junctions linked by trails going right or down,
+/// with slopes at both ends, some of the trails missing. `size` is the number of junctions on
+/// a side of the lattice, from 2 to 6.
+impl Generate for HikingMap {
+    fn generate(random: &mut Random, size: usize) -> String {
+        let count = size.clamp(2, 6);
+        let mut place = |start| {
+            let mut coordinates = vec![start];
+            while coordinates.len() < count {
+                let last = coordinates[coordinates.len() - 1];
+                coordinates.push(last + random.size(3..=6));
+            }
+            coordinates
+        };
+        let (xs, ys) = (place(1), place(1));
+        let (width, height) = (xs[count - 1] + 2, ys[count - 1] + 2);
+        let mut tiles = Grid::filled(width, height, '#');
+        tiles[(1, 0)] = '.';
+        tiles[(xs[count - 1], height - 1)] = '.';
+        for (j, &y) in ys.iter().enumerate() {
+            for (i, &x) in xs.iter().enumerate() {
+                tiles[(x, y)] = '.';
+                // the top row and every column keep all their trails, so that the lattice is
+                // connected
+                if i + 1 < count && (j == 0 || random.chance(70)) {
+                    for trail_x in x + 1..xs[i + 1] {
+                        tiles[(trail_x, y)] = '.';
+                    }
+                    tiles[(x + 1, y)] = '>';
+                    tiles[(xs[i + 1] - 1, y)] = '>';
+                }
+                if j + 1 < count {
+                    for trail_y in y + 1..ys[j + 1] {
+                        tiles[(x, trail_y)] = '.';
+                    }
+                    tiles[(x, y + 1)] = 'v';
+                    tiles[(x, ys[j + 1] - 1)] = 'v';
+                }
+            }
+        }
+        tiles.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::solution::{Answer, Solution};
+
+    use crate::{HikingMap, NoHike};
+
+    #[test]
+    fn trails() {
+        let input = include_str!("../../fixtures/day-23-test");
+        let map = HikingMap::try_from(input).unwrap();
+        assert_eq!((map.start, map.end), ((1, 0), (21, 22)));
+        let trails = map.trails(false);
+        assert_eq!(trails.junctions.len(), 9);
+        assert_eq!(trails.junctions[2], (3, 5));
+        assert_eq!(trails.edges[0], vec![(2, 15)]);
+        // the slopes only let the trail from the start be walked one way
+        let trails = map.trails(true);
+        assert!(trails.edges[2].iter().all(|&(next, _)| next != 0));
+    }
+
+    #[test]
+    fn open_room() {
+        let mut input = vec!["#.##########"];
+        input.extend(["#..........#"; 8]);
+        input.push("##########.#");
+        let map = HikingMap::try_from(input.join("\n").as_str()).unwrap();
+        assert_eq!(map.longest_hike(false), Err(NoHike::TooManyJunctions(80)));
+        assert_eq!(
+            map.part1(),
+            Answer::Text("80 junctions, too many to try every hike".to_string())
+        );
+    }
+
+    #[test]
+    fn invalid_maps() {
+        let error = HikingMap::try_from("#.#\n#x#\n#.#").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 2));
+        let error = HikingMap::try_from("#.#\n#.#\n###").err().unwrap();
+        assert_eq!((error.line, error.column), (3, 1));
+    }
+}
@@ -0,0 +1,351 @@
+use common::{
+    generate::{Generate, Random},
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+use num::{BigInt, BigRational, One, ToPrimitive, Zero};
+use std::ops::RangeInclusive;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Hailstone {
+    pub position: [i128; 3],
+    pub velocity: [i128; 3],
+}
+
+impl Hailstone {
+    /// Where the paths of two hailstones cross in the X and Y plane, ignoring Z, if both
+    /// hailstones get there in the future. The crossing is given as the numerators of `x` and
+    /// `y` over a positive common denominator, so that no precision is lost.
+    pub fn crossing_2d(&self, other: &Hailstone) -> Option<(i128, i128, i128)> {
+        let ([x1, y1, _], [vx1, vy1, _]) = (self.position, self.velocity);
+        let ([x2, y2, _], [vx2, vy2, _]) = (other.position, other.velocity);
+        // solving (x1, y1) + t (vx1, vy1) = (x2, y2) + s (vx2, vy2) with Cramer's rule
+        let mut denominator = vx1 * vy2 - vy1 * vx2;
+        if denominator == 0 {
+            return None;
+        }
+        let (dx, dy) = (x2 - x1, y2 - y1);
+        let mut t = dx * vy2 - dy * vx2;
+        let mut s = dx * vy1 - dy * vx1;
+        if denominator < 0 {
+            (denominator, t, s) = (-denominator, -t, -s);
+        }
+        if t < 0 || s < 0 {
+            return None;
+        }
+        Some((
+            x1 * denominator + t * vx1,
+            y1 * denominator + t * vy1,
+            denominator,
+        ))
+    }
+}
+
+impl TryFrom<&str> for Hailstone {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let (position, velocity) = line
+            .split_once('@')
+            .ok_or_else(|| ParseError::after(line, "`@`"))?;
+        let vector = |text: &str| -> Result<[i128; 3], ParseError> {
+            let coordinates = text
+                .split(',')
+                .map(|coordinate| parse::number(line, coordinate))
+                .collect::<Result<Vec<i128>, _>>()?;
+            coordinates
+                .try_into()
+                .map_err(|_| ParseError::at(line, text, "three coordinates `x, y, z`"))
+        };
+        Ok(Self {
+            position: vector(position)?,
+            velocity: vector(velocity)?,
+        })
+    }
+}
+
+pub struct Hailstorm {
+    pub hailstones: Vec<Hailstone>,
+}
+
+impl Hailstorm {
+    /// The number of pairs of hailstones whose future paths cross inside the test area.
+    pub fn count_crossings(&self, area: RangeInclusive<i128>) -> usize {
+        let is_inside = |numerator: i128, denominator: i128| {
+            area.start() * denominator <= numerator && numerator <= area.end() * denominator
+        };
+        self.hailstones
+            .iter()
+            .enumerate()
+            .flat_map(|(index, a)| self.hailstones[index + 1..].iter().map(move |b| (a, b)))
+            .filter_map(|(a, b)| a.crossing_2d(b))
+            .filter(|&(x, y, denominator)| is_inside(x, denominator) && is_inside(y, denominator))
+            .count()
+    }
+
+    /// The rock thrown so that it hits every hailstone.
+    ///
+    /// The rock at `P` with velocity `V` hits the hailstone `i` when `P - p_i` and `V - v_i`
+    /// are parallel, so `(P - p_i) × (V - v_i) = 0`. The `P × V` term is the same for every
+    /// hailstone: subtracting the equations of two pairs of hailstones leaves six linear
+    /// equations, solved with exact rationals.
+    pub fn rock(&self) -> Option<Hailstone> {
+        let count = self.hailstones.len();
+        // parallel hailstones give dependent equations, another triple is then tried
+        let triples = (0..count).flat_map(move |i| {
+            (i + 1..count).flat_map(move |j| (j + 1..count).map(move |k| (i, j, k)))
+        });
+        for (i, j, k) in triples {
+            let (a, b, c) = (
+                &self.hailstones[i],
+                &self.hailstones[j],
+                &self.hailstones[k],
+            );
+            let mut system = equations(a, b);
+            system.extend(equations(a, c));
+            if let Some(solution) = solve(system) {
+                // a rock thrown from between integer positions does not count
+                let integers = solution
+                    .iter()
+                    .map(|value| match value.is_integer() {
+                        true => value.to_integer().to_i128(),
+                        false => None,
+                    })
+                    .collect::<Option<Vec<i128>>>()?;
+                let rock = Hailstone {
+                    position: [integers[0], integers[1], integers[2]],
+                    velocity: [integers[3], integers[4], integers[5]],
+                };
+                return Some(rock);
+            }
+        }
+        None
+    }
+}
+
+// the three equations `P × (v_b - v_a) + (p_b - p_a) × V = p_b × v_b - p_a × v_a` on the six
+// unknowns `P` and `V`, each given as its coefficients followed by its constant
+fn equations(a: &Hailstone, b: &Hailstone) -> Vec<[i128; 7]> {
+    let dv = sub(b.velocity, a.velocity);
+    let dp = sub(b.position, a.position);
+    let constant = sub(cross(b.position, b.velocity), cross(a.position, a.velocity));
+    // one equation per component of the cross products
+    (0..3)
+        .map(|axis| {
+            let (next, last) = ((axis + 1) % 3, (axis + 2) % 3);
+            let mut row = [0; 7];
+            // P × dv: P[next] dv[last] - P[last] dv[next]
+            row[next] += dv[last];
+            row[last] -= dv[next];
+            // dp × V: dp[next] V[last] - dp[last] V[next]
+            row[3 + last] += dp[next];
+            row[3 + next] -= dp[last];
+            row[6] = constant[axis];
+            row
+        })
+        .collect()
+}
+
+fn sub(u: [i128; 3], w: [i128; 3]) -> [i128; 3] {
+    [u[0] - w[0], u[1] - w[1], u[2] - w[2]]
+}
+
+fn cross(u: [i128; 3], w: [i128; 3]) -> [i128; 3] {
+    [
+        u[1] * w[2] - u[2] * w[1],
+        u[2] * w[0] - u[0] * w[2],
+        u[0] * w[1] - u[1] * w[0],
+    ]
+}
+
+// Gauss-Jordan elimination of a square system, none when it has no single solution
+fn solve(system: Vec<[i128; 7]>) -> Option<Vec<BigRational>> {
+    let size = system.len();
+    let mut rows: Vec<Vec<BigRational>> = system
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|&value| BigRational::from_integer(BigInt::from(value)))
+                .collect()
+        })
+        .collect();
+    for column in 0..size {
+        let pivot = (column..size).find(|&row| !rows[row][column].is_zero())?;
+        rows.swap(column, pivot);
+        let factor = BigRational::one() / &rows[column][column];
+        rows[column] = rows[column].iter().map(|value| value * &factor).collect();
+        for row in 0..size {
+            if row != column && !rows[row][column].is_zero() {
+                let factor = rows[row][column].clone();
+                rows[row] = rows[row]
+                    .iter()
+                    .zip(rows[column].iter())
+                    .map(|(value, pivot_value)| value - &factor * pivot_value)
+                    .collect();
+            }
+        }
+    }
+    Some(rows.into_iter().map(|row| row[size].clone()).collect())
+}
+
+impl TryFrom<&str> for Hailstorm {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let hailstones = parse::lines(input, |line| Hailstone::try_from(line))?;
+        Ok(Self { hailstones })
+    }
+}
+
+impl Solution for Hailstorm {
+    const DAY: u8 = 24;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Hailstorm::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        self.count_crossings(200000000000000..=400000000000000)
+            .into()
+    }
+
+    fn part2(&self) -> Answer {
+        match self.rock() {
+            Some(rock) => {
+                let sum: i128 = rock.position.iter().sum();
+                match i64::try_from(sum) {
+                    Ok(sum) => sum.into(),
+                    Err(_) => sum.to_string().into(),
+                }
+            }
+            None => "no rock hits every hailstone".into(),
+        }
+    }
+}
+
+/// Hailstones all hit by a rock thrown from an integer position at an integer velocity, at
+/// distinct times, their positions around the puzzle's test area. `size` is the number of
+/// hailstones, 3 at least.
+impl Generate for Hailstorm {
+    fn generate(random: &mut Random, size: usize) -> String {
+        let mut coordinates =
+            |range: RangeInclusive<i64>| [(); 3].map(|_| random.signed(range.clone()) as i128);
+        let position = coordinates(200000000000000..=400000000000000);
+        let velocity = coordinates(-300..=300);
+        let mut times = vec![];
+        let mut input = String::new();
+        while times.len() < size.max(3) {
+            let time = random.signed(1..=1_000_000_000_000) as i128;
+            let hailstone_velocity = [(); 3].map(|_| random.signed(-300..=300) as i128);
+            if times.contains(&time) || hailstone_velocity == velocity {
+                continue;
+            }
+            times.push(time);
+            // where the hailstone starts so that the rock meets it at `time`
+            let hailstone_position = [0, 1, 2]
+                .map(|axis| position[axis] + time * (velocity[axis] - hailstone_velocity[axis]));
+            let format =
+                |vector: [i128; 3]| vector.map(|coordinate| coordinate.to_string()).join(", ");
+            input.push_str(&format!(
+                "{} @ {}\n",
+                format(hailstone_position),
+                format(hailstone_velocity)
+            ));
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::generate::{Generate, Random};
+
+    use crate::{Hailstone, Hailstorm};
+
+    #[test]
+    fn parse_hailstone() {
+        let hailstone = Hailstone::try_from("20, 19, 15 @  1, -5, -3").unwrap();
+        assert_eq!(
+            hailstone,
+            Hailstone {
+                position: [20, 19, 15],
+                velocity: [1, -5, -3]
+            }
+        );
+    }
+
+    #[test]
+    fn crossings() {
+        let input = include_str!("../../fixtures/day-24-test");
+        let hailstorm = Hailstorm::try_from(input).unwrap();
+        let [a, b, c, ..] = &hailstorm.hailstones[..] else {
+            unreachable!()
+        };
+        // 14.333, 15.333
+        assert_eq!(a.crossing_2d(b), Some((43, 46, 3)));
+        // parallel paths
+        assert_eq!(b.crossing_2d(c), None);
+        assert_eq!(hailstorm.count_crossings(7..=27), 2);
+    }
+
+    #[test]
+    fn exact_crossings() {
+        // the paths cross at 200000000000000.5, just outside an area ending at 2 * 10^14
+        let a = Hailstone::try_from("0, 0, 0 @ 1, 1, 0").unwrap();
+        let b = Hailstone::try_from("400000000000001, 0, 0 @ -1, 1, 0").unwrap();
+        let hailstorm = Hailstorm {
+            hailstones: vec![a, b],
+        };
+        assert_eq!(hailstorm.count_crossings(0..=200000000000000), 0);
+        assert_eq!(hailstorm.count_crossings(0..=200000000000001), 1);
+    }
+
+    #[test]
+    fn rock() {
+        let input = include_str!("../../fixtures/day-24-test");
+        let hailstorm = Hailstorm::try_from(input).unwrap();
+        assert_eq!(
+            hailstorm.rock(),
+            Some(Hailstone {
+                position: [24, 13, 10],
+                velocity: [-3, 1, 2]
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_hailstones() {
+        let error = Hailstorm::try_from("19, 13, 30 @ -2, 1, -2\n18, 19 @ -1, -1, -2")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+        let error = Hailstorm::try_from("19, 13, 30 -2, 1, -2").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 21));
+        let error = Hailstorm::try_from("19, 13, 30 @ -2, x, -2").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 18));
+    }
+
+    #[test]
+    fn generated_hailstorms() {
+        for seed in 0..3 {
+            let input = Hailstorm::generate(&mut Random::new(seed), 5);
+            let hailstorm = Hailstorm::try_from(input.as_str()).unwrap();
+            let rock = hailstorm.rock().unwrap();
+            // the rock and each hailstone are at the same place at some time `t`
+            for hailstone in hailstorm.hailstones.iter() {
+                let times: Vec<i128> = (0..3)
+                    .filter(|&axis| rock.velocity[axis] != hailstone.velocity[axis])
+                    .map(|axis| {
+                        (hailstone.position[axis] - rock.position[axis])
+                            / (rock.velocity[axis] - hailstone.velocity[axis])
+                    })
+                    .collect();
+                let t = times[0];
+                assert!((0..3).all(|axis| {
+                    rock.position[axis] + t * rock.velocity[axis]
+                        == hailstone.position[axis] + t * hailstone.velocity[axis]
+                }));
+            }
+        }
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    common::solution::main::<day_24::Hailstorm>();
+}
@@ -0,0 +1,289 @@
+use common::{
+    generate::{Generate, Random},
+    parse::ParseError,
+    solution::{Answer, Solution},
+};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+/// A set of wires whose removal splits the components in two groups.
+#[derive(Debug, PartialEq)]
+pub struct Cut {
+    pub wires: Vec<(String, String)>,
+    pub sizes: (usize, usize),
+}
+
+impl Cut {
+    pub fn product(&self) -> usize {
+        self.sizes.0 * self.sizes.1
+    }
+}
+
+pub struct Wiring {
+    pub names: Vec<String>,
+    pub wires: Vec<(usize, usize)>,
+    // for each component, its neighbours and the index of the wire leading to them
+    pub neighbours: Vec<Vec<(usize, usize)>>,
+}
+
+impl Wiring {
+    /// The maximum flow from `source` to `sink`, each wire carrying one unit in either
+    /// direction, found with Edmonds–Karp. The search stops once the flow is above `limit`.
+    ///
+    /// Also returns the components still reachable from the source: when the flow is at most
+    /// `limit`, they are one side of a minimum cut.
+    pub fn max_flow(&self, source: usize, sink: usize, limit: usize) -> (usize, Vec<bool>) {
+        // the flow through each wire, positive from its first component to its second one
+        let mut flows = vec![0_i8; self.wires.len()];
+        let mut flow = 0;
+        loop {
+            let mut previous: Vec<Option<(usize, usize)>> = vec![None; self.names.len()];
+            let mut reached = vec![false; self.names.len()];
+            reached[source] = true;
+            let mut queue = VecDeque::from([source]);
+            while let Some(component) = queue.pop_front() {
+                if component == sink {
+                    break;
+                }
+                for &(next, wire) in self.neighbours[component].iter() {
+                    let forward = self.wires[wire].0 == component;
+                    let residual = if forward {
+                        1 - flows[wire]
+                    } else {
+                        1 + flows[wire]
+                    };
+                    if residual > 0 && !reached[next] {
+                        reached[next] = true;
+                        previous[next] = Some((component, wire));
+                        queue.push_back(next);
+                    }
+                }
+            }
+            if !reached[sink] || flow == limit + 1 {
+                return (flow, reached);
+            }
+            let mut component = sink;
+            while let Some((before, wire)) = previous[component] {
+                flows[wire] += if self.wires[wire].0 == before { 1 } else { -1 };
+                component = before;
+            }
+            flow += 1;
+        }
+    }
+
+    /// The smallest set of wires splitting the components in two groups: the minimum cut
+    /// between the first component and any other one.
+    pub fn min_cut(&self) -> Option<Cut> {
+        let mut best: Option<(usize, Vec<bool>)> = None;
+        for sink in 1..self.names.len() {
+            // only a smaller cut can improve on the best one, and none is smaller than no wire
+            let limit = match &best {
+                None => self.wires.len(),
+                Some((flow, _)) => match flow.checked_sub(1) {
+                    Some(limit) => limit,
+                    None => break,
+                },
+            };
+            let (flow, reached) = self.max_flow(0, sink, limit);
+            if flow <= limit {
+                best = Some((flow, reached));
+            }
+        }
+        let (_, reached) = best?;
+        let wires = self
+            .wires
+            .iter()
+            .filter(|&&(a, b)| reached[a] != reached[b])
+            .map(|&(a, b)| (self.names[a].clone(), self.names[b].clone()))
+            .collect();
+        let size = reached.iter().filter(|&&reached| reached).count();
+        Some(Cut {
+            wires,
+            sizes: (size, self.names.len() - size),
+        })
+    }
+}
+
+impl TryFrom<&str> for Wiring {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut indices: BTreeMap<&str, usize> = BTreeMap::new();
+        let mut names = vec![];
+        let mut index = |name| {
+            *indices.entry(name).or_insert_with(|| {
+                names.push(name.to_string());
+                names.len() - 1
+            })
+        };
+        let mut wires = vec![];
+        for (line_index, line) in input.lines().enumerate() {
+            let (name, others) = line
+                .split_once(": ")
+                .ok_or_else(|| ParseError::after(line, "`: `").on_line(line_index))?;
+            if others.trim().is_empty() {
+                return Err(ParseError::after(line, "a connected component").on_line(line_index));
+            }
+            let component = index(name);
+            for other in others.split_whitespace() {
+                wires.push((component, index(other)));
+            }
+        }
+        let mut neighbours = vec![vec![]; names.len()];
+        for (wire, &(a, b)) in wires.iter().enumerate() {
+            neighbours[a].push((b, wire));
+            neighbours[b].push((a, wire));
+        }
+        Ok(Self {
+            names,
+            wires,
+            neighbours,
+        })
+    }
+}
+
+impl Solution for Wiring {
+    const DAY: u8 = 25;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Wiring::try_from(input)
+    }
+
+    fn part1(&self) -> Answer {
+        match self.min_cut() {
+            Some(cut) => cut.product().into(),
+            None => "no wires to cut".into(),
+        }
+    }
+
+    fn part2(&self) -> Answer {
+        "there is no part 2 on the last day".into()
+    }
+}
+
+/// Two groups of components linked by exactly three wires. Each component of a group is wired
+/// to the two next ones around a ring, and to a few random others, so that splitting a group
+/// takes four wires at least and the three wires are the only minimum cut. `size` is the number
+/// of components of each group, 5 at least.
+impl Generate for Wiring {
+    fn generate(random: &mut Random, size: usize) -> String {
+        let count = size.max(5);
+        let mut names: Vec<String> = vec![];
+        while names.len() < 2 * count {
+            let name = random.string(3, "abcdefghijklmnopqrstuvwxyz");
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        // a wire and its reverse are the same wire
+        let mut wires = BTreeSet::new();
+        let mut wire = |a: usize, b: usize| wires.insert((a.min(b), a.max(b)));
+        for group in [0, count] {
+            for index in 0..count {
+                for next in [1, 2] {
+                    wire(group + index, group + (index + next) % count);
+                }
+                let other = group + random.size(0..=count - 1);
+                if other != group + index && random.chance(30) {
+                    wire(group + index, other);
+                }
+            }
+        }
+        let mut cut = vec![];
+        while cut.len() < 3 {
+            let wire = (
+                random.size(0..=count - 1),
+                count + random.size(0..=count - 1),
+            );
+            if !cut.contains(&wire) {
+                cut.push(wire);
+            }
+        }
+        wires.extend(cut);
+        // each wire is listed once, on the line of either of its components
+        let mut lines: BTreeMap<usize, Vec<&str>> = BTreeMap::new();
+        for (a, b) in wires {
+            let (a, b) = match random.chance(50) {
+                true => (a, b),
+                false => (b, a),
+            };
+            lines.entry(a).or_default().push(&names[b]);
+        }
+        let mut lines: Vec<String> = lines
+            .into_iter()
+            .map(|(component, others)| format!("{}: {}", names[component], others.join(" ")))
+            .collect();
+        random.shuffle(&mut lines);
+        lines.join("\n") + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::generate::{Generate, Random};
+
+    use crate::Wiring;
+
+    #[test]
+    fn parse() {
+        let input = include_str!("../../fixtures/day-25-test");
+        let wiring = Wiring::try_from(input).unwrap();
+        assert_eq!(wiring.names.len(), 15);
+        assert_eq!(wiring.wires.len(), 33);
+        assert_eq!(wiring.names[wiring.wires[0].1], "rhn");
+    }
+
+    #[test]
+    fn max_flow() {
+        let input = include_str!("../../fixtures/day-25-test");
+        let wiring = Wiring::try_from(input).unwrap();
+        let index = |name| wiring.names.iter().position(|n| n == name).unwrap();
+        let (flow, _) = wiring.max_flow(index("jqt"), index("rsh"), 10);
+        assert_eq!(flow, 3);
+        let (flow, _) = wiring.max_flow(index("jqt"), index("rsh"), 1);
+        assert_eq!(flow, 2);
+    }
+
+    #[test]
+    fn min_cut() {
+        let input = include_str!("../../fixtures/day-25-test");
+        let wiring = Wiring::try_from(input).unwrap();
+        let cut = wiring.min_cut().unwrap();
+        let mut wires: Vec<(String, String)> = cut
+            .wires
+            .into_iter()
+            .map(|(a, b)| if a < b { (a, b) } else { (b, a) })
+            .collect();
+        wires.sort();
+        let expected = [("bvb", "cmg"), ("hfx", "pzl"), ("jqt", "nvd")]
+            .map(|(a, b)| (a.to_string(), b.to_string()));
+        assert_eq!(wires, expected);
+        assert_eq!(cut.sizes, (6, 9));
+    }
+
+    #[test]
+    fn disconnected_wiring() {
+        let wiring = Wiring::try_from("a: b\nc: d\nb: e").unwrap();
+        let cut = wiring.min_cut().unwrap();
+        assert!(cut.wires.is_empty());
+        assert_eq!(cut.sizes, (3, 2));
+    }
+
+    #[test]
+    fn invalid_wiring() {
+        let error = Wiring::try_from("jqt: rhn\nrsh frs").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 8));
+        let error = Wiring::try_from("jqt: rhn\nrsh: ").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 6));
+    }
+
+    #[test]
+    fn generated_wirings() {
+        for seed in 0..3 {
+            let input = Wiring::generate(&mut Random::new(seed), 8);
+            let wiring = Wiring::try_from(input.as_str()).unwrap();
+            let cut = wiring.min_cut().unwrap();
+            assert_eq!(cut.wires.len(), 3);
+            assert_eq!(cut.sizes, (8, 8));
+        }
+    }
+}
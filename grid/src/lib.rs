@@ -0,0 +1,405 @@
+use common::{
+    generate::Random,
+    parse::{self, ParseError},
+};
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+/// A position in a grid: `(x, y)`, the column and the row from the top left corner.
+pub type Position = (usize, usize);
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    pub fn opposite(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+
+    /// The direction of a single step from a position to a neighbouring one.
+    pub fn between((x1, y1): Position, (x2, y2): Position) -> Option<Self> {
+        match (x2 as isize - x1 as isize, y2 as isize - y1 as isize) {
+            (0, -1) => Some(Direction::Up),
+            (0, 1) => Some(Direction::Down),
+            (-1, 0) => Some(Direction::Left),
+            (1, 0) => Some(Direction::Right),
+            _ => None,
+        }
+    }
+}
+
+/// A rectangle of cells, stored row by row.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its rows, which must all have the same length.
+    fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map(|row| row.len()).unwrap_or_default();
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "the rows of a grid have the same length"
+        );
+        let cells = rows.into_iter().flatten().collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses a grid from text, a line per row and a character per cell.
+    pub fn parse<F>(input: &str, cell: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Result<T, ParseError>,
+    {
+        parse::grid(input, cell).map(Self::from_rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Position) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        if self.contains(position) {
+            Some(&self.cells[position.1 * self.width + position.0])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if self.contains(position) {
+            Some(&mut self.cells[position.1 * self.width + position.0])
+        } else {
+            None
+        }
+    }
+
+    /// The position next to the given one in a direction, if it is still in the grid.
+    pub fn step(&self, (x, y): Position, direction: Direction) -> Option<Position> {
+        let next = match direction {
+            Direction::Up => (x, y.checked_sub(1)?),
+            Direction::Down => (x, y + 1),
+            Direction::Left => (x.checked_sub(1)?, y),
+            Direction::Right => (x + 1, y),
+        };
+        Some(next).filter(|&next| self.contains(next))
+    }
+
+    /// The positions above, below, left and right of the given one, in the grid.
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// The positions around the given one, diagonals included, in the grid.
+    pub fn neighbours8(&self, (x, y): Position) -> impl Iterator<Item = Position> + '_ {
+        (y.saturating_sub(1)..=y + 1)
+            .flat_map(move |ny| (x.saturating_sub(1)..=x + 1).map(move |nx| (nx, ny)))
+            .filter(move |&neighbour| neighbour != (x, y) && self.contains(neighbour))
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn cells_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// The position of the first cell matching the predicate, row by row.
+    pub fn find<P>(&self, mut predicate: P) -> Option<Position>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "the column is in the grid");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Self {
+        self.rebuild(self.height, self.width, |(x, y)| (y, x))
+    }
+
+    /// Rotates a quarter turn clockwise: the left column becomes the top row.
+    pub fn rotate_clockwise(&self) -> Self {
+        let height = self.height;
+        self.rebuild(self.height, self.width, |(x, y)| (y, height - 1 - x))
+    }
+
+    /// Rotates a quarter turn counterclockwise: the top row becomes the left column.
+    pub fn rotate_counterclockwise(&self) -> Self {
+        let width = self.width;
+        self.rebuild(self.height, self.width, |(x, y)| (width - 1 - y, x))
+    }
+
+    // builds a grid of the given size, each cell being copied from the position given by `source`
+    fn rebuild<F>(&self, width: usize, height: usize, source: F) -> Self
+    where
+        F: Fn(Position) -> Position,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|position| self[source(position)].clone())
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl TryFrom<&str> for Grid<char> {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        Grid::parse(input, Ok)
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        self.get(position).expect("the position is in the grid")
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        self.get_mut(position).expect("the position is in the grid")
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// A random loop through the positions of a `width` by `height` area, at least 2 by 2, which
+/// never touches itself: the positions are given in the order the loop visits them, the last one
+/// being next to the first one.
+///
+/// The loop goes around a shape made of columns of cells, each column overlapping the previous
+/// one, so that the shape has neither holes nor cells touching only by a corner.
+pub fn random_loop(random: &mut Random, width: usize, height: usize) -> Vec<Position> {
+    assert!(width >= 2 && height >= 2, "an area of 2 by 2 or more");
+    // the cells are between the positions, each column of cells covers the rows `top..=bottom`
+    let rows = height - 1;
+    let top = random.size(0..=rows - 1);
+    let mut spans = vec![(top, random.size(top..=rows - 1))];
+    while spans.len() < width - 1 {
+        let (top, bottom) = spans[spans.len() - 1];
+        let next_top = random.size(0..=bottom);
+        let next_bottom = random.size(next_top.max(top)..=rows - 1);
+        spans.push((next_top, next_bottom));
+    }
+    let is_filled = |x: usize, y: usize| {
+        // the cell at the bottom right of position `(x, y)`, shifted by one to fit in a usize
+        x > 0 && y > 0 && spans.get(x - 1).is_some_and(|&(t, b)| t < y && y <= b + 1)
+    };
+    // an edge of the loop separates a filled cell from an empty one
+    let has_edge = |(x, y): Position, direction| match direction {
+        Direction::Up => is_filled(x, y) != is_filled(x + 1, y),
+        Direction::Down => is_filled(x, y + 1) != is_filled(x + 1, y + 1),
+        Direction::Left => is_filled(x, y) != is_filled(x, y + 1),
+        Direction::Right => is_filled(x + 1, y) != is_filled(x + 1, y + 1),
+    };
+    let start = (0, spans[0].0);
+    let mut path = vec![start];
+    let (mut position, mut direction) = (start, Direction::Right);
+    loop {
+        position = match direction {
+            Direction::Up => (position.0, position.1 - 1),
+            Direction::Down => (position.0, position.1 + 1),
+            Direction::Left => (position.0 - 1, position.1),
+            Direction::Right => (position.0 + 1, position.1),
+        };
+        if position == start {
+            return path;
+        }
+        path.push(position);
+        direction = Direction::ALL
+            .into_iter()
+            .find(|&next| next != direction.opposite() && has_edge(position, next))
+            .expect("the loop goes on");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::generate::Random;
+
+    use crate::{random_loop, Direction, Grid};
+
+    fn grid() -> Grid<char> {
+        Grid::try_from("abc\ndef").unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.get((3, 0)), None);
+        assert!(Grid::try_from("abc\nde").is_err());
+        let error = Grid::try_from("é\nab").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(Grid::try_from("éa\nbç").unwrap()[(1, 1)], 'ç');
+        let digits = Grid::parse("12\n34", |c| Ok(c.to_digit(10).unwrap())).unwrap();
+        assert_eq!(digits.cells().sum::<u32>(), 10);
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = grid();
+        let mut neighbours: Vec<_> = grid.neighbours4((0, 0)).collect();
+        neighbours.sort();
+        assert_eq!(neighbours, vec![(0, 1), (1, 0)]);
+        let mut neighbours: Vec<_> = grid.neighbours8((1, 0)).collect();
+        neighbours.sort();
+        assert_eq!(neighbours, vec![(0, 0), (0, 1), (1, 1), (2, 0), (2, 1)]);
+        assert_eq!(grid.step((2, 1), Direction::Right), None);
+        assert_eq!(grid.step((2, 1), Direction::Up), Some((2, 0)));
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = grid();
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+        assert_eq!(columns, vec!["ad", "be", "cf"]);
+        assert_eq!(grid.find(|&c| c == 'e'), Some((1, 1)));
+    }
+
+    #[test]
+    fn transformations() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(
+            grid.rotate_clockwise().rotate_counterclockwise(),
+            grid.clone()
+        );
+        assert_eq!(
+            grid.map(|c| c.to_ascii_uppercase()).to_string(),
+            "ABC\nDEF\n"
+        );
+    }
+
+    #[test]
+    fn random_loops() {
+        let mut random = Random::new(7);
+        for (width, height) in [(2, 2), (3, 8), (12, 5)] {
+            let path = random_loop(&mut random, width, height);
+            let mut positions = path.clone();
+            positions.sort();
+            positions.dedup();
+            assert_eq!(positions.len(), path.len());
+            assert!(positions.iter().all(|&(x, y)| x < width && y < height));
+            let closed = path.iter().zip(path.iter().cycle().skip(1));
+            assert!(closed
+                .into_iter()
+                .all(|(&a, &b)| Direction::between(a, b).is_some()));
+        }
+        let square = random_loop(&mut random, 2, 2);
+        assert_eq!(square, vec![(0, 0), (1, 0), (1, 1), (0, 1)]);
+    }
+}